@@ -0,0 +1,230 @@
+use std::any::Any;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use crate::{DigitCodeError, DigitCodeProfile, Grapheme, Grouping, Masking, TotpCodeProfile};
+
+/// The object safe part of `DigitCodeProfile`, it can be used as `dyn DynDigitCodeProfile`.
+///
+/// It's implemented for every `DigitCodeProfile`, so you never have to implement it yourself.
+/// It's exported in `extra` because its methods have the same names as the ones of `DigitCodeProfile`.
+/// The generic `check_char_code` is available as `check_chars` that takes a slice.
+#[allow(clippy::len_without_is_empty)]
+pub trait DynDigitCodeProfile: Debug {
+    /// See `DigitCodeProfile::len`
+    fn len(&self) -> usize;
+    /// See `DigitCodeProfile::min_len`
+    fn min_len(&self) -> usize;
+    /// See `DigitCodeProfile::char_matches_alphabet_impl`
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool;
+    /// See `DigitCodeProfile::is_valid_char`
+    fn is_valid_char(&self, chr: &str) -> bool;
+    /// See `DigitCodeProfile::char_matches_alphabet_at`
+    fn char_matches_alphabet_at(&self, index: usize, char: &str) -> bool;
+    /// See `DigitCodeProfile::is_valid_char_at`
+    fn is_valid_char_at(&self, index: usize, chr: &str) -> bool;
+    /// See `DigitCodeProfile::normalize_char`
+    fn normalize_char(&self, chr: &str) -> Grapheme;
+    /// See `DigitCodeProfile::input_mode`
+    fn input_mode(&self, index: usize) -> &str;
+    /// See `DigitCodeProfile::masking`
+    fn masking(&self) -> Masking;
+    /// See `DigitCodeProfile::grouping`
+    fn grouping(&self) -> Grouping;
+    /// See `DigitCodeProfile::is_separator`
+    fn is_separator(&self, chr: &str) -> bool;
+    /// See `DigitCodeProfile::split_code`
+    fn split_code<'a>(&self, code: &'a str) -> Vec<&'a str>;
+    /// See `DigitCodeProfile::normalize_code`
+    fn normalize_code(&self, code: &str) -> Vec<Grapheme>;
+    /// See `DigitCodeProfile::validate_code`
+    fn validate_code(&self, code: &str) -> bool;
+    /// `DigitCodeProfile::check_char_code` with the chars of a slice
+    fn check_chars(&self, chars: &[&str]) -> Result<String, DigitCodeError>;
+    /// See `DigitCodeProfile::check_str_code`
+    fn check_str_code(&self, code: &str) -> Result<String, DigitCodeError>;
+
+    /// The profile as `Any`, used to compare two boxed profiles
+    fn as_any(&self) -> &dyn Any;
+    /// Checks if the other profile has the same type and is equal to this one
+    fn dyn_eq(&self, other: &dyn DynDigitCodeProfile) -> bool;
+}
+
+impl<PROFILE: DigitCodeProfile + 'static> DynDigitCodeProfile for PROFILE {
+    fn len(&self) -> usize {
+        DigitCodeProfile::len(self)
+    }
+    fn min_len(&self) -> usize {
+        DigitCodeProfile::min_len(self)
+    }
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool {
+        DigitCodeProfile::char_matches_alphabet_impl(self, char)
+    }
+    fn is_valid_char(&self, chr: &str) -> bool {
+        DigitCodeProfile::is_valid_char(self, chr)
+    }
+    fn char_matches_alphabet_at(&self, index: usize, char: &str) -> bool {
+        DigitCodeProfile::char_matches_alphabet_at(self, index, char)
+    }
+    fn is_valid_char_at(&self, index: usize, chr: &str) -> bool {
+        DigitCodeProfile::is_valid_char_at(self, index, chr)
+    }
+    fn normalize_char(&self, chr: &str) -> Grapheme {
+        DigitCodeProfile::normalize_char(self, chr)
+    }
+    fn input_mode(&self, index: usize) -> &str {
+        DigitCodeProfile::input_mode(self, index)
+    }
+    fn masking(&self) -> Masking {
+        DigitCodeProfile::masking(self)
+    }
+    fn grouping(&self) -> Grouping {
+        DigitCodeProfile::grouping(self)
+    }
+    fn is_separator(&self, chr: &str) -> bool {
+        DigitCodeProfile::is_separator(self, chr)
+    }
+    fn split_code<'a>(&self, code: &'a str) -> Vec<&'a str> {
+        DigitCodeProfile::split_code(self, code)
+    }
+    fn normalize_code(&self, code: &str) -> Vec<Grapheme> {
+        DigitCodeProfile::normalize_code(self, code)
+    }
+    fn validate_code(&self, code: &str) -> bool {
+        DigitCodeProfile::validate_code(self, code)
+    }
+    fn check_chars(&self, chars: &[&str]) -> Result<String, DigitCodeError> {
+        self.check_char_code(chars.iter().copied())
+    }
+    fn check_str_code(&self, code: &str) -> Result<String, DigitCodeError> {
+        DigitCodeProfile::check_str_code(self, code)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn dyn_eq(&self, other: &dyn DynDigitCodeProfile) -> bool {
+        other
+            .as_any()
+            .downcast_ref::<PROFILE>()
+            .is_some_and(|other| other == self)
+    }
+}
+
+/// A profile whose type is chosen at runtime, e.g. to switch between a TOTP and a backup code.
+///
+/// It wraps any profile as `Arc<dyn DynDigitCodeProfile>` and implements `DigitCodeProfile` itself,
+/// so a single `CodeDigitInput<BoxedProfile>` (or `BoxedInput`) can show all of them.
+/// Two boxed profiles are equal if the wrapped profiles have the same type and are equal.
+/// The default is a 6 digit `TotpCodeProfile`.
+///
+/// ```
+/// use yew_digit_code::{
+///     BoxedProfile, CrockfordCodeProfile, DigitCodeProfile, PinCodeProfile, TotpCodeProfile,
+/// };
+///
+/// let backup_code = true;
+/// let profile = if backup_code {
+///     BoxedProfile::new(CrockfordCodeProfile::<10>::new())
+/// } else {
+///     BoxedProfile::new(TotpCodeProfile::<6>::new())
+/// };
+/// assert_eq!(profile.len(), 10);
+/// assert_eq!(profile.check_str_code("abcdefghjk"), Ok("ABCDEFGHJK".to_string()));
+///
+/// assert_eq!(BoxedProfile::default(), BoxedProfile::new(TotpCodeProfile::<6>::new()));
+/// assert_ne!(BoxedProfile::default(), BoxedProfile::new(TotpCodeProfile::<4>::new()));
+/// assert_ne!(
+///     BoxedProfile::new(TotpCodeProfile::<4>::new()),
+///     BoxedProfile::new(PinCodeProfile::<4>::new())
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoxedProfile(Arc<dyn DynDigitCodeProfile>);
+
+impl BoxedProfile {
+    /// Wraps the profile
+    pub fn new<PROFILE: DigitCodeProfile + 'static>(profile: PROFILE) -> Self {
+        Self(Arc::new(profile))
+    }
+    /// The wrapped profile
+    pub fn inner(&self) -> &dyn DynDigitCodeProfile {
+        &*self.0
+    }
+    /// The wrapped profile if it has the type `PROFILE`
+    pub fn downcast_ref<PROFILE: DigitCodeProfile + 'static>(&self) -> Option<&PROFILE> {
+        self.0.as_any().downcast_ref()
+    }
+}
+
+impl From<Arc<dyn DynDigitCodeProfile>> for BoxedProfile {
+    fn from(profile: Arc<dyn DynDigitCodeProfile>) -> Self {
+        Self(profile)
+    }
+}
+
+impl Default for BoxedProfile {
+    fn default() -> Self {
+        Self::new(TotpCodeProfile::<6>::new())
+    }
+}
+
+impl PartialEq for BoxedProfile {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0.dyn_eq(&*other.0)
+    }
+}
+
+impl DigitCodeProfile for BoxedProfile {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+    fn min_len(&self) -> usize {
+        self.0.min_len()
+    }
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool {
+        self.0.char_matches_alphabet_impl(char)
+    }
+    fn is_valid_char(&self, chr: &str) -> bool {
+        self.0.is_valid_char(chr)
+    }
+    fn char_matches_alphabet_at(&self, index: usize, char: &str) -> bool {
+        self.0.char_matches_alphabet_at(index, char)
+    }
+    fn is_valid_char_at(&self, index: usize, chr: &str) -> bool {
+        self.0.is_valid_char_at(index, chr)
+    }
+    fn normalize_char(&self, chr: &str) -> Grapheme {
+        self.0.normalize_char(chr)
+    }
+    fn input_mode(&self, index: usize) -> &str {
+        self.0.input_mode(index)
+    }
+    fn masking(&self) -> Masking {
+        self.0.masking()
+    }
+    fn grouping(&self) -> Grouping {
+        self.0.grouping()
+    }
+    fn is_separator(&self, chr: &str) -> bool {
+        self.0.is_separator(chr)
+    }
+    fn split_code<'a>(&self, code: &'a str) -> Vec<&'a str> {
+        self.0.split_code(code)
+    }
+    fn normalize_code(&self, code: &str) -> Vec<Grapheme> {
+        self.0.normalize_code(code)
+    }
+    fn validate_code(&self, code: &str) -> bool {
+        self.0.validate_code(code)
+    }
+    fn check_char_code<'a, I: Iterator<Item = &'a str>>(
+        &'a self,
+        chars: I,
+    ) -> Result<String, DigitCodeError> {
+        self.0.check_chars(&chars.collect::<Vec<_>>())
+    }
+    fn check_str_code(&self, code: &str) -> Result<String, DigitCodeError> {
+        self.0.check_str_code(code)
+    }
+}
@@ -0,0 +1,261 @@
+//! Check digit algorithms and a profile wrapper that rejects codes with a wrong check digit.
+//!
+//! `ChecksumProfile` wraps any profile and additionally verifies the complete code with a
+//! `ChecksumAlgorithm`. The type aliases like `LuhnProfile` select one of the predefined algorithms.
+//!
+//! ```
+//! use std::sync::Arc;
+//! use yew_digit_code::checksum::{
+//!     ChecksumAlgorithm, Damm, DammProfile, Iso7064Mod11_2, Iso7064Mod97_10, Luhn, LuhnProfile,
+//!     Verhoeff,
+//! };
+//! use yew_digit_code::{
+//!     DigitCodeEditor, DigitCodeError, DigitCodeProfile, EditorEffect, EditorEvent,
+//!     TotpCodeProfile,
+//! };
+//!
+//! assert!(Luhn::verify("79927398713"));
+//! assert!(!Luhn::verify("79927398710"));
+//! assert!(Damm::verify("5724"));
+//! assert!(!Damm::verify("5274"));
+//! assert!(Verhoeff::verify("2363"));
+//! assert!(!Verhoeff::verify("2336"));
+//! assert!(Iso7064Mod11_2::verify("0000000218250097"));
+//! assert!(!Iso7064Mod11_2::verify("000000021825009X"));
+//! assert!(Iso7064Mod97_10::verify("WEST12345698765432GB82"));
+//! assert!(Iso7064Mod97_10::verify("123482"));
+//!
+//! let profile = LuhnProfile::new(TotpCodeProfile::<11>::new());
+//! assert!(profile.is_str_code_valid("79927398713"));
+//! assert!(!profile.is_str_code_valid("79927398710"));
+//!
+//! let profile = DammProfile::<TotpCodeProfile<4>>::default();
+//! assert!(profile.validate_code("5724"));
+//! assert!(!profile.validate_code("5274"));
+//!
+//! // codes with a wrong check digit aren't submitted
+//! let mut editor = DigitCodeEditor::new(Arc::new(profile));
+//! let effects = editor.handle(EditorEvent::Paste { index: 0, text: "5274".into() });
+//! let error = DigitCodeError::ChecksumFailed { code: "5274".into() };
+//! assert_eq!(effects.last(), Some(&EditorEffect::Invalid(error)));
+//! editor.handle(EditorEvent::SetValue("572".into()));
+//! let effects = editor.handle(EditorEvent::Input { index: 3, value: "4".into() });
+//! assert_eq!(effects.last(), Some(&EditorEffect::Submit("5724".into())));
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::{DigitCodeProfile, Grapheme, Grouping, Masking};
+
+/// An algorithm that verifies the check digit of a complete code
+pub trait ChecksumAlgorithm: PartialEq + Clone + Default + Debug {
+    /// Checks if the code (including its check digit) is valid
+    fn verify(code: &str) -> bool;
+}
+
+/// Wraps a profile and rejects complete codes that fail the checksum algorithm `C`.
+///
+/// Everything else is taken from the inner profile.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ChecksumProfile<PROFILE: DigitCodeProfile, C: ChecksumAlgorithm> {
+    inner: PROFILE,
+    algorithm: PhantomData<C>,
+}
+
+impl<PROFILE: DigitCodeProfile, C: ChecksumAlgorithm> ChecksumProfile<PROFILE, C> {
+    /// Adds the checksum to the profile
+    pub fn new(inner: PROFILE) -> Self {
+        Self {
+            inner,
+            algorithm: PhantomData,
+        }
+    }
+    /// The wrapped profile
+    pub fn inner(&self) -> &PROFILE {
+        &self.inner
+    }
+}
+
+impl<PROFILE: DigitCodeProfile, C: ChecksumAlgorithm> DigitCodeProfile
+    for ChecksumProfile<PROFILE, C>
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+    fn min_len(&self) -> usize {
+        self.inner.min_len()
+    }
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool {
+        self.inner.char_matches_alphabet_impl(char)
+    }
+    fn char_matches_alphabet_at(&self, index: usize, char: &str) -> bool {
+        self.inner.char_matches_alphabet_at(index, char)
+    }
+    fn normalize_char(&self, chr: &str) -> Grapheme {
+        self.inner.normalize_char(chr)
+    }
+    fn input_mode(&self, index: usize) -> &str {
+        self.inner.input_mode(index)
+    }
+    fn masking(&self) -> Masking {
+        self.inner.masking()
+    }
+    fn grouping(&self) -> Grouping {
+        self.inner.grouping()
+    }
+    fn is_separator(&self, chr: &str) -> bool {
+        self.inner.is_separator(chr)
+    }
+    fn validate_code(&self, code: &str) -> bool {
+        self.inner.validate_code(code) && C::verify(code)
+    }
+}
+
+/// A profile with the Luhn checksum, e.g. for gift card numbers
+pub type LuhnProfile<PROFILE> = ChecksumProfile<PROFILE, Luhn>;
+/// A profile with the Damm checksum
+pub type DammProfile<PROFILE> = ChecksumProfile<PROFILE, Damm>;
+/// A profile with the Verhoeff checksum
+pub type VerhoeffProfile<PROFILE> = ChecksumProfile<PROFILE, Verhoeff>;
+/// A profile with the ISO 7064 MOD 11-2 checksum
+pub type Iso7064Mod11_2Profile<PROFILE> = ChecksumProfile<PROFILE, Iso7064Mod11_2>;
+/// A profile with the ISO 7064 MOD 97-10 checksum
+pub type Iso7064Mod97_10Profile<PROFILE> = ChecksumProfile<PROFILE, Iso7064Mod97_10>;
+
+/// The digits of a decimal code, `None` if it contains another char
+fn decimal_digits(code: &str) -> Option<Vec<usize>> {
+    code.chars()
+        .map(|c| c.to_digit(10).map(|d| d as usize))
+        .collect()
+}
+
+/// Luhn algorithm (mod 10), the last digit is the check digit
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Luhn;
+
+impl ChecksumAlgorithm for Luhn {
+    fn verify(code: &str) -> bool {
+        let Some(digits) = decimal_digits(code) else {
+            return false;
+        };
+        let sum: usize = digits
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, &d)| match (i % 2, d * 2) {
+                (0, _) => d,
+                (_, doubled) if doubled > 9 => doubled - 9,
+                (_, doubled) => doubled,
+            })
+            .sum();
+        !digits.is_empty() && sum % 10 == 0
+    }
+}
+
+/// Damm algorithm, detects all single digit errors and adjacent transpositions
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Damm;
+
+impl ChecksumAlgorithm for Damm {
+    fn verify(code: &str) -> bool {
+        const TABLE: [[usize; 10]; 10] = [
+            [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+            [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+            [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+            [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+            [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+            [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+            [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+            [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+            [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+            [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+        ];
+        decimal_digits(code).is_some_and(|digits| {
+            !digits.is_empty() && digits.iter().fold(0, |interim, &d| TABLE[interim][d]) == 0
+        })
+    }
+}
+
+/// Verhoeff algorithm, based on the dihedral group D5
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Verhoeff;
+
+impl ChecksumAlgorithm for Verhoeff {
+    fn verify(code: &str) -> bool {
+        const MULTIPLICATION: [[usize; 10]; 10] = [
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+            [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+            [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+            [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+            [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+            [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+            [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+            [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+            [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+            [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+        ];
+        const PERMUTATION: [[usize; 10]; 8] = [
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+            [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+            [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+            [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+            [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+            [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+            [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+            [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+        ];
+        decimal_digits(code).is_some_and(|digits| {
+            let check = digits
+                .iter()
+                .rev()
+                .enumerate()
+                .fold(0, |c, (i, &d)| MULTIPLICATION[c][PERMUTATION[i % 8][d]]);
+            !digits.is_empty() && check == 0
+        })
+    }
+}
+
+/// ISO 7064 MOD 11-2, the check char is a digit or `X` (e.g. ORCID iDs)
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Iso7064Mod11_2;
+
+impl ChecksumAlgorithm for Iso7064Mod11_2 {
+    fn verify(code: &str) -> bool {
+        let mut chars = code.chars();
+        let check = match chars.next_back() {
+            Some('X') => 10,
+            Some(c) => match c.to_digit(10) {
+                Some(d) => d as usize,
+                None => return false,
+            },
+            None => return false,
+        };
+        let Some(digits) = decimal_digits(chars.as_str()) else {
+            return false;
+        };
+        let sum = digits.iter().fold(0, |sum, &d| (sum + d) * 2 % 11);
+        (12 - sum) % 11 == check
+    }
+}
+
+/// ISO 7064 MOD 97-10 for digits and letters `A-Z` (e.g. the check digits of an IBAN)
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Iso7064Mod97_10;
+
+impl ChecksumAlgorithm for Iso7064Mod97_10 {
+    fn verify(code: &str) -> bool {
+        let mut rest = 0;
+        for c in code.chars() {
+            let Some(value) = c
+                .to_digit(36)
+                .filter(|_| c.is_ascii_digit() || c.is_ascii_uppercase())
+            else {
+                return false;
+            };
+            let shift = if value > 9 { 100 } else { 10 };
+            rest = (rest * shift + value) % 97;
+        }
+        !code.is_empty() && rest == 1
+    }
+}
@@ -1,38 +1,98 @@
-use crate::inner_code_element::InnerCodeDigitInput;
-use crate::{ControlFlags, DigitCode, DigitCodeProfile};
+use crate::inner_code_element::{InnerCodeDigitInput, Props as InnerProps};
+use crate::{
+    ControlFlags, DigitCodeHandle, DigitCodeProfile, Masking, PartialCode, SubmitErrorPolicy,
+    SubmitFuture,
+};
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct Props<PROFILE: DigitCodeProfile + 'static> {
-    #[cfg(feature = "default-id")]
     #[prop_or_default]
     pub id: Option<AttrValue>,
-    #[cfg(not(feature = "default-id"))]
-    pub id: AttrValue,
     #[prop_or_default]
     pub submit_code: Option<Callback<String>>,
     #[prop_or_default]
+    pub submit_code_async: Option<Callback<String, SubmitFuture>>,
+    #[prop_or_default]
+    pub submit_error_policy: SubmitErrorPolicy,
+    #[prop_or_default]
     pub profile: Option<PROFILE>,
     #[prop_or_default]
     pub flags: Option<UseStateHandle<ControlFlags>>,
     #[prop_or_default]
     pub class: Classes,
-    #[cfg(feature = "yew-hooks")]
+    #[prop_or_default]
+    pub one_time_code: bool,
+    #[prop_or_default]
+    pub masking: Option<Masking>,
+    #[prop_or_default]
+    pub mask_peek: Option<std::time::Duration>,
+    #[prop_or_default]
+    pub value: Option<PartialCode>,
+    #[prop_or_default]
+    pub onchange: Option<Callback<PartialCode>>,
+    #[prop_or_default]
+    pub oninvalid: Option<Callback<crate::DigitCodeError>>,
     #[prop_or_default]
     pub oninit: Callback<AttrValue>,
+    #[prop_or_default]
+    pub handle: Option<DigitCodeHandle>,
+    #[prop_or_default]
+    pub onready: Option<Callback<DigitCodeHandle>>,
+    #[cfg(feature = "web-otp")]
+    #[prop_or_default]
+    pub web_otp: bool,
+    #[cfg(feature = "web-otp")]
+    #[prop_or_default]
+    pub web_otp_timeout: Option<std::time::Duration>,
+    #[cfg(feature = "web-otp")]
+    #[prop_or_default]
+    pub onwebotp: Callback<crate::WebOtpOutcome>,
 }
 
 /// This is the general input component for a code of multiple digits.
 ///
-/// - It needs a _UNIQUE_ id, if the `default-id` feature is enabled it will be selected automatically with `rand` crate
+/// - The `id` of the container is optional, the digits are focussed with node references.
+///   So the component works inside a shadow DOM or an iframe. If the `default-id` feature is enabled a random id is generated
+///   with `rand` crate when none is given
 /// - Additionally you can provide the attribute `class` with extra html classes (yew classes!() macro)
 /// - By default the generic profile type's default object will be used as `profile`.
 ///   If you want more control you can provide an instance as value of the `profile` attribute.
+///   Whenever the profile changes the digits get cleared. Use `DynamicCodeProfile` if the length or alphabet is only known at runtime
+///   and `BoxedProfile` (see `BoxedInput`) to switch between profiles of different types.
+/// - Profiles with a `min_len` below their `len` accept codes of variable length: `Enter` submits the code once the minimum
+///   is reached and it's submitted automatically when every digit is filled. Unused trailing digits have the css class `unused`.
 /// - The code the user wants to submit will be send to the `submit_code` callback
+/// - Alternatively `submit_code_async` receives the code and returns a future. While it's pending the digits are disabled
+///   and the container has the css class `pending`. Afterwards it has the class `success` or `error` until the next edit.
+///   If the code was rejected `submit_error_policy` decides what happens with it.
+/// - `masking` hides the digits, e.g. for PINs. By default the masking of the profile is used.
+///   With `mask_peek` a typed digit is shown in clear text for the given duration.
+///   The `reveal` and `mask` flags of `ControlFlags` show or hide all digits.
+/// - A whole code pasted into any digit replaces all digits. Shorter texts get spread across the following digits, they're rejected if they don't fit.
+///   Separators and whitespace are dropped (see `DigitCodeProfile::split_code`)
+/// - Set `one_time_code` to let mobile browsers offer codes received by SMS (`autocomplete="one-time-code"` on the first digit).
+///   The inserted code gets spread across the digits like a pasted one.
 /// - If you want to perform additional actions like reset the whole edit you can provide a state with `ControlFlags` to influence the behaviour to `flags`.
-/// - If you want to do something as soon as the document is ready and flags can be processed use the `oninit` attribute to provide a callback
+/// - Alternatively pass a `DigitCodeHandle` to `handle` (or get the one of the component from `onready`, called once after mounting)
+///   and call its methods like `focus`, `clear`, `fill` or `submit`. The commands are queued, so none of them gets lost
+///   and `current` returns the digits at any time
+/// - `onchange` receives all digits after every edit, even if the code is incomplete.
+///   With `value` the parent controls the digits: they're compared after every render and replaced if they differ.
+///   Feed the code of `onchange` back into `value` to let the user edit it. Gaps between digits are kept
+/// - A complete code that fails `DigitCodeProfile::validate_code` (e.g. a wrong check digit, see `checksum`) isn't submitted.
+///   Whenever an input is rejected the container gets the css class `invalid` until the next edit and `oninvalid` receives
+///   a `DigitCodeError` with the reason: an invalid char, a failed checksum or missing digits if `Enter` was pressed too early.
+/// - If you need different markup use the `use_digit_code` hook instead
+/// - If you want to do something as soon as the digits are mounted and flags can be processed use the `oninit` attribute to provide a callback.
+///   It's called exactly once after the first render
 ///
-/// If the `yew-hooks` feature is enabled you can also set a default feature collection when defining the outer state. This will get processed before calling `oninit`.
+/// If the `web-otp` feature is enabled `web_otp` requests the code of an incoming SMS with the
+/// [WebOTP API](https://developer.mozilla.org/en-US/docs/Web/API/WebOTP_API) after initialization.
+/// A code that is valid for the profile gets filled in and submitted. The request is aborted after the optional
+/// `web_otp_timeout` or when the component gets removed. Every outcome is reported to `onwebotp`.
+///
+/// You can also set a default feature collection when defining the outer state. It gets processed right after mounting, before calling `oninit`.
 ///
 /// # Examples
 ///
@@ -50,6 +110,99 @@ pub struct Props<PROFILE: DigitCodeProfile + 'static> {
 /// }
 /// ```
 ///
+/// Codes received by SMS can be offered by the browser of mobile devices
+///
+/// ```
+/// use yew_digit_code::TotpInput;
+/// use yew::prelude::*;
+///
+/// #[function_component(SmsCodeComponent)]
+/// fn sms_code_component() -> Html {
+///     let submit_code = Callback::from(move |code: String| {
+///         log::info!("Submit: {code}");
+///     });
+///
+///     html!(<TotpInput submit_code={submit_code} one_time_code={true}/>)
+/// }
+/// ```
+///
+/// Verified asynchronously by a server
+///
+/// ```
+/// use yew_digit_code::{SubmitErrorPolicy, SubmitFuture, TotpInput};
+/// use yew::prelude::*;
+///
+/// async fn verify(code: String) -> Result<(), String> {
+///     if code == "123456" {
+///         Ok(())
+///     } else {
+///         Err("Wrong code".to_string())
+///     }
+/// }
+///
+/// #[function_component(AsyncCodeComponent)]
+/// fn async_code_component() -> Html {
+///     let submit_code_async =
+///         Callback::from(move |code: String| -> SubmitFuture { Box::pin(verify(code)) });
+///
+///     html!(<TotpInput submit_code_async={submit_code_async} submit_error_policy={SubmitErrorPolicy::Shake}/>)
+/// }
+/// ```
+///
+/// A masked PIN that shows each digit for a moment and can be revealed with a button
+///
+/// ```
+/// use std::time::Duration;
+/// use yew_digit_code::{ControlFlags, Masking, TotpInput};
+/// use yew::prelude::*;
+///
+/// #[function_component(PinComponent)]
+/// fn pin_component() -> Html {
+///     let flags = use_state_eq(|| ControlFlags::default());
+///     let revealed = use_state_eq(|| false);
+///     let onclick = {
+///         let flags = flags.clone();
+///         let revealed = revealed.clone();
+///         Callback::from(move |_| {
+///             let builder = flags.change();
+///             flags.set(if *revealed { builder.mask() } else { builder.reveal() }.apply());
+///             revealed.set(!*revealed);
+///         })
+///     };
+///
+///     html!(
+///         <>
+///             <TotpInput<4> masking={Masking::Glyph('•')} mask_peek={Duration::from_millis(500)} flags={flags}/>
+///             <button {onclick}>{"Show / hide"}</button>
+///         </>
+///     )
+/// }
+/// ```
+///
+/// Controlled by the parent, prefilled with two digits and updated on every edit
+///
+/// ```
+/// use yew_digit_code::{PartialCode, TotpInput};
+/// use yew::prelude::*;
+///
+/// #[function_component(ControlledCodeComponent)]
+/// fn controlled_code_component() -> Html {
+///     let code = use_state(|| PartialCode {
+///         digits: vec![Some("1".into()), None, Some("3".into())],
+///         complete: false,
+///     });
+///     let onchange = {
+///         let code = code.clone();
+///         Callback::from(move |new_code: PartialCode| {
+///             log::info!("Changed: {:?}, complete: {}", new_code.digits, new_code.complete);
+///             code.set(new_code);
+///         })
+///     };
+///
+///     html!(<TotpInput value={(*code).clone()} onchange={onchange}/>)
+/// }
+/// ```
+///
 /// To clear and focus first on submit
 ///
 /// ```
@@ -79,11 +232,26 @@ pub fn code_digit_element<PROFILE: DigitCodeProfile + 'static>(
     Props {
         id,
         submit_code,
+        submit_code_async,
+        submit_error_policy,
         flags,
         profile,
         class,
-        #[cfg(feature = "yew-hooks")]
+        one_time_code,
+        masking,
+        mask_peek,
+        value,
+        onchange,
+        oninvalid,
         oninit,
+        handle,
+        onready,
+        #[cfg(feature = "web-otp")]
+        web_otp,
+        #[cfg(feature = "web-otp")]
+        web_otp_timeout,
+        #[cfg(feature = "web-otp")]
+        onwebotp,
     }: &Props<PROFILE>,
 ) -> Html {
     let default_state_handle_flags = use_state_eq(ControlFlags::default);
@@ -91,43 +259,51 @@ pub fn code_digit_element<PROFILE: DigitCodeProfile + 'static>(
 
     #[cfg(feature = "default-id")]
     let id = {
-        let id_state = use_state_eq(|| {
+        let generated_id = use_state_eq(|| {
             use rand::{distributions::Alphanumeric, Rng};
 
-            if let Some(id) = id {
-                id.to_string()
-            } else {
-                let rng = rand::thread_rng();
-
-                let random_alphanumeric: String = rng
-                    .sample_iter(&Alphanumeric)
-                    .take(50)
-                    .map(char::from)
-                    .collect();
-                // must start with a letter
-                format!("digit-code-edit-{random_alphanumeric}")
-            }
+            let rng = rand::thread_rng();
+            let random_alphanumeric: String = rng
+                .sample_iter(&Alphanumeric)
+                .take(50)
+                .map(char::from)
+                .collect();
+            // must start with a letter
+            AttrValue::from(format!("digit-code-edit-{random_alphanumeric}"))
         });
-        (*id_state).clone()
-    };
-
-    let id = id.to_string();
-    let id = if !id.starts_with(['0', '1', '2', '3', '4', '5', '6', '7', '8', '9']) {
-        id
-    } else {
-        #[cfg(feature = "log")]
-        log::warn!("Your choosen id isn't valid. It needs to start with a letter");
-        format!("d-{id}")
+        Some(id.clone().unwrap_or_else(|| (*generated_id).clone()))
     };
+    #[cfg(not(feature = "default-id"))]
+    let id = id.clone();
 
     let profile = profile.clone().unwrap_or_default();
     let flags = flags.clone();
     let submit_code = submit_code.clone();
 
-    let whole_code_state = use_state(|| DigitCode::new(std::sync::Arc::new(profile.clone())));
-
-    #[cfg(not(feature = "yew-hooks"))]
-    return html!(<InnerCodeDigitInput<PROFILE> class={class} id={id} submit_code={submit_code} flags={flags} code={whole_code_state}/>);
-    #[cfg(feature = "yew-hooks")]
-    return html!(<InnerCodeDigitInput<PROFILE> class={class} id={id} submit_code={submit_code} flags={flags} code={whole_code_state} oninit={oninit}/>);
+    #[allow(unused_mut)]
+    let mut inner_props = yew::props!(InnerProps<PROFILE> {
+        class: class.clone(),
+        id,
+        submit_code,
+        submit_code_async: submit_code_async.clone(),
+        submit_error_policy: *submit_error_policy,
+        flags,
+        profile,
+        one_time_code: *one_time_code,
+        masking: *masking,
+        mask_peek: *mask_peek,
+        value: value.clone(),
+        onchange: onchange.clone(),
+        oninvalid: oninvalid.clone(),
+        oninit: oninit.clone(),
+        handle: handle.clone(),
+        onready: onready.clone(),
+    });
+    #[cfg(feature = "web-otp")]
+    {
+        inner_props.web_otp = *web_otp;
+        inner_props.web_otp_timeout = *web_otp_timeout;
+        inner_props.onwebotp = onwebotp.clone();
+    }
+    html!(<InnerCodeDigitInput<PROFILE> ..inner_props/>)
 }
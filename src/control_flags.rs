@@ -1,6 +1,28 @@
+use crate::handle::Command;
+use crate::{DigitCodeError, SubmitState};
+
 /// This struct stores all flags that can be used to send commands to the component
 ///
-/// With `change` you can create a builder and edit flags.
+/// With `change` you can create a builder and edit flags. The component processes the flags
+/// right after they changed (or after mounting) and resets them afterwards.
+///
+/// If several flags are set they're processed in this order, so e.g. a focus sees the new value:
+///
+/// 1. `clear`
+/// 2. `set_value`
+/// 3. `set_digit` and `clear_digit`, in the order they were added
+/// 4. `reveal` or `mask`
+/// 5. `disable` or `enable`
+/// 6. `readonly` or `editable`
+/// 7. `mark_invalid` or `show_error`
+/// 8. `focus_first`, `focus_index`, `focus_last`, `focus_first_empty` or `focus_last_empty`
+/// 9. `select_all`
+/// 10. `submit`
+///
+/// Flags of the same step replace each other, the last call of the builder wins.
+/// Edits reset the state of `mark_invalid` and `show_error` like user input does,
+/// that's why they're processed afterwards.
+/// Focus and selection are applied after the next render, so they work on digits that are enabled by the same flags.
 ///
 /// # Examples
 /// Focus first digit and clear all digits when code gets submitted (using standard TOTP input)
@@ -26,18 +48,63 @@
 ///     )
 /// }
 /// ```
+///
+/// Prefill a code, mark it as wrong and let the user correct it
+/// ```
+/// use yew_digit_code::{ControlFlags, DigitCodeError, TotpInput};
+/// use yew::prelude::*;
+///
+/// #[function_component(CorrectCodeComponent)]
+/// fn correct_code_component() -> Html {
+///     let flags = use_state_eq(|| {
+///         ControlFlags::default()
+///             .change()
+///             .set_value("12345")
+///             .mark_invalid(DigitCodeError::Incomplete { missing: vec![5] })
+///             .focus_first_empty()
+///             .apply()
+///     });
+///     html!(<TotpInput flags={flags}/>)
+/// }
+/// ```
 #[derive(Debug, PartialEq, Default, Eq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ControlFlags {
-    pub(crate) focus: Option<usize>,
+    pub(crate) focus: Option<FocusTarget>,
     pub(crate) clear: Option<()>,
+    pub(crate) value: Option<String>,
+    pub(crate) digits: Vec<(usize, Option<String>)>,
+    pub(crate) reveal: Option<bool>,
+    pub(crate) disabled: Option<bool>,
+    pub(crate) readonly: Option<bool>,
+    pub(crate) status: Option<SubmitState>,
+    pub(crate) select_all: Option<()>,
+    pub(crate) submit: Option<()>,
+}
+
+/// The digit a `ControlFlags` focus command refers to
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum FocusTarget {
+    Index(usize),
+    Last,
+    FirstEmpty,
+    LastEmpty,
 }
 
 /// Builds the `ControlFlags` struct
 #[derive(Debug, PartialEq, Eq)]
 pub struct ControlFlagsBuilder {
-    pub(crate) focus: Option<usize>,
+    pub(crate) focus: Option<FocusTarget>,
     pub(crate) clear: Option<()>,
+    pub(crate) value: Option<String>,
+    pub(crate) digits: Vec<(usize, Option<String>)>,
+    pub(crate) reveal: Option<bool>,
+    pub(crate) disabled: Option<bool>,
+    pub(crate) readonly: Option<bool>,
+    pub(crate) status: Option<SubmitState>,
+    pub(crate) select_all: Option<()>,
+    pub(crate) submit: Option<()>,
 }
 
 impl ControlFlags {
@@ -46,14 +113,80 @@ impl ControlFlags {
         ControlFlagsBuilder {
             focus: self.focus,
             clear: self.clear,
+            value: self.value.clone(),
+            digits: self.digits.clone(),
+            reveal: self.reveal,
+            disabled: self.disabled,
+            readonly: self.readonly,
+            status: self.status.clone(),
+            select_all: self.select_all,
+            submit: self.submit,
         }
     }
+
+    /// The commands of the flags in the documented order
+    pub(crate) fn commands(self) -> Vec<Command> {
+        let ControlFlags {
+            focus,
+            clear,
+            value,
+            digits,
+            reveal,
+            disabled,
+            readonly,
+            status,
+            select_all,
+            submit,
+        } = self;
+        let status = match status {
+            Some(SubmitState::Invalid(error)) => Some(Command::MarkInvalid(error)),
+            Some(SubmitState::Error(message)) => Some(Command::ShowError(message)),
+            _ => None,
+        };
+
+        clear
+            .map(|_| Command::Clear)
+            .into_iter()
+            .chain(value.map(Command::SetValue))
+            .chain(
+                digits
+                    .into_iter()
+                    .map(|(index, value)| Command::SetDigit(index, value)),
+            )
+            .chain(reveal.map(Command::SetRevealed))
+            .chain(disabled.map(Command::SetDisabled))
+            .chain(readonly.map(Command::SetReadonly))
+            .chain(status)
+            .chain(focus.map(Command::Focus))
+            .chain(select_all.map(|_| Command::SelectAll))
+            .chain(submit.map(|_| Command::Submit))
+            .collect()
+    }
 }
 
 impl ControlFlagsBuilder {
     /// Set the flag to focus first digit
-    pub fn focus_first(mut self) -> Self {
-        self.focus = Some(0);
+    pub fn focus_first(self) -> Self {
+        self.focus_index(0)
+    }
+    /// Set the flag to focus the digit at `index`
+    pub fn focus_index(mut self, index: usize) -> Self {
+        self.focus = Some(FocusTarget::Index(index));
+        self
+    }
+    /// Set the flag to focus the last digit
+    pub fn focus_last(mut self) -> Self {
+        self.focus = Some(FocusTarget::Last);
+        self
+    }
+    /// Set the flag to focus the first empty digit, or the last one if every digit is filled
+    pub fn focus_first_empty(mut self) -> Self {
+        self.focus = Some(FocusTarget::FirstEmpty);
+        self
+    }
+    /// Set the flag to focus the last empty digit, or the last one if every digit is filled
+    pub fn focus_last_empty(mut self) -> Self {
+        self.focus = Some(FocusTarget::LastEmpty);
         self
     }
     /// Unset focus flag
@@ -71,11 +204,126 @@ impl ControlFlagsBuilder {
         self.clear = None;
         self
     }
+    /// Set flag to replace all digits with the text, see `EditorEvent::SetValue`
+    pub fn set_value(mut self, text: impl Into<String>) -> Self {
+        self.value = Some(text.into());
+        self
+    }
+    /// Unset flag to replace all digits
+    pub fn unset_value(mut self) -> Self {
+        self.value = None;
+        self
+    }
+    /// Add flag to set the digit at `index`, invalid chars are rejected like typed ones
+    pub fn set_digit(mut self, index: usize, chr: impl Into<String>) -> Self {
+        self.digits.push((index, Some(chr.into())));
+        self
+    }
+    /// Add flag to remove the digit at `index`
+    pub fn clear_digit(mut self, index: usize) -> Self {
+        self.digits.push((index, None));
+        self
+    }
+    /// Unset all flags of `set_digit` and `clear_digit`
+    pub fn unset_digits(mut self) -> Self {
+        self.digits.clear();
+        self
+    }
+    /// Set flag to show masked digits in clear text until `mask` is used
+    pub fn reveal(mut self) -> Self {
+        self.reveal = Some(true);
+        self
+    }
+    /// Set flag to mask the digits again after `reveal`
+    pub fn mask(mut self) -> Self {
+        self.reveal = Some(false);
+        self
+    }
+    /// Unset flag to reveal or mask the digits
+    pub fn unset_reveal(mut self) -> Self {
+        self.reveal = None;
+        self
+    }
+    /// Set flag to disable all digits until `enable` is used
+    pub fn disable(mut self) -> Self {
+        self.disabled = Some(true);
+        self
+    }
+    /// Set flag to enable the digits again after `disable`
+    pub fn enable(mut self) -> Self {
+        self.disabled = Some(false);
+        self
+    }
+    /// Unset flag to disable or enable the digits
+    pub fn unset_disabled(mut self) -> Self {
+        self.disabled = None;
+        self
+    }
+    /// Set flag to make the digits readonly until `editable` is used.
+    ///
+    /// They can still be focussed and the code can be submitted with `Enter`.
+    pub fn readonly(mut self) -> Self {
+        self.readonly = Some(true);
+        self
+    }
+    /// Set flag to make the digits editable again after `readonly`
+    pub fn editable(mut self) -> Self {
+        self.readonly = Some(false);
+        self
+    }
+    /// Unset flag to make the digits readonly or editable
+    pub fn unset_readonly(mut self) -> Self {
+        self.readonly = None;
+        self
+    }
+    /// Set flag to show the code as invalid (css class `invalid`) until the next edit
+    pub fn mark_invalid(mut self, error: DigitCodeError) -> Self {
+        self.status = Some(SubmitState::Invalid(error));
+        self
+    }
+    /// Set flag to show the code as rejected (css class `error`) until the next edit, like a failed asynchronous submission
+    pub fn show_error(mut self, message: impl Into<String>) -> Self {
+        self.status = Some(SubmitState::Error(message.into()));
+        self
+    }
+    /// Unset flag of `mark_invalid` or `show_error`
+    pub fn unset_status(mut self) -> Self {
+        self.status = None;
+        self
+    }
+    /// Set flag to select every digit, so the next typed char or `Backspace` replaces the whole code
+    pub fn select_all(mut self) -> Self {
+        self.select_all = Some(());
+        self
+    }
+    /// Unset flag to select every digit
+    pub fn unset_select_all(mut self) -> Self {
+        self.select_all = None;
+        self
+    }
+    /// Set flag to submit the code like `Enter` does, incomplete codes are marked as invalid
+    pub fn submit(mut self) -> Self {
+        self.submit = Some(());
+        self
+    }
+    /// Unset flag to submit the code
+    pub fn unset_submit(mut self) -> Self {
+        self.submit = None;
+        self
+    }
     /// Create `ControlFlags` for the current flag configuration
     pub fn apply(self) -> ControlFlags {
         ControlFlags {
             focus: self.focus,
             clear: self.clear,
+            value: self.value,
+            digits: self.digits,
+            reveal: self.reveal,
+            disabled: self.disabled,
+            readonly: self.readonly,
+            status: self.status,
+            select_all: self.select_all,
+            submit: self.submit,
         }
     }
 }
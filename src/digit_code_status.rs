@@ -1,16 +1,38 @@
-use crate::DigitCodeProfile;
+use crate::{DigitCodeError, DigitCodeProfile, Grapheme};
 #[cfg(feature = "itertools")]
 use itertools::Itertools;
 use std::sync::Arc;
 
-#[cfg(feature = "unicode-segmentation")]
-pub type ArcStrOrChar = Arc<str>;
-#[cfg(not(feature = "unicode-segmentation"))]
-pub type ArcStrOrChar = char;
+/// The text of a single digit, it's the same type with and without `unicode-segmentation`
+#[deprecated(note = "use `Grapheme`")]
+pub type ArcStrOrChar = Grapheme;
 
+/// A snapshot of all digits, including incomplete codes.
+///
+/// This is what the `onchange` callback of `CodeDigitInput` receives after every edit.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartialCode {
+    /// The value of every digit, `None` for empty ones
+    pub digits: Vec<Option<String>>,
+    /// Is `true` if the code is complete and valid
+    pub complete: bool,
+}
+
+impl PartialCode {
+    /// The code as text if it's complete
+    pub fn joined(&self) -> Option<String> {
+        self.complete
+            .then(|| self.digits.iter().flatten().map(String::as_str).collect())
+    }
+}
+
+/// The state of all digits of a code for a profile.
+///
+/// Every digit is either empty or contains a char that is valid for the profile.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct DigitCode<PROFILE: DigitCodeProfile + 'static> {
-    code: Vec<Option<ArcStrOrChar>>,
+    code: Vec<Option<Grapheme>>,
     profile: Arc<PROFILE>,
     update_indicator: i64,
 }
@@ -22,6 +44,7 @@ impl<PROFILE: DigitCodeProfile + 'static> Default for DigitCode<PROFILE> {
 }
 
 impl<PROFILE: DigitCodeProfile + 'static> DigitCode<PROFILE> {
+    /// Creates an empty code for the profile
     pub fn new(profile: Arc<PROFILE>) -> Self {
         Self {
             code: vec![None; profile.len()],
@@ -29,52 +52,111 @@ impl<PROFILE: DigitCodeProfile + 'static> DigitCode<PROFILE> {
             update_indicator: 0,
         }
     }
-    pub fn set(&mut self, index: usize, value: Option<ArcStrOrChar>) -> Option<()> {
-        if index < self.code.len() {
-            if let Some(value) = value.clone() {
-                if !self.profile.is_valid_char(&value) {
-                    return None;
-                }
+    /// Sets or removes the digit at `index`.
+    ///
+    /// Fails if the index is out of range or the char is invalid at this position
+    pub fn set(&mut self, index: usize, value: Option<Grapheme>) -> Result<(), DigitCodeError> {
+        if index >= self.code.len() {
+            return Err(DigitCodeError::OutOfRange { index });
+        }
+        if let Some(value) = &value {
+            if !self.profile.is_valid_char_at(index, value) {
+                return Err(DigitCodeError::InvalidChar {
+                    index,
+                    value: value.to_string(),
+                });
             }
-            self.code[index] = value;
-            Some(())
-        } else {
-            None
         }
+        self.code[index] = value;
+        Ok(())
+    }
+    /// Writes the chars into consecutive digits starting at `start`.
+    ///
+    /// Nothing gets changed if one of the chars is invalid, chars that don't fit are ignored.
+    /// The chars aren't normalized, see `DigitCodeProfile::normalize_code`.
+    /// Returns the index after the last written digit.
+    pub fn fill(&mut self, start: usize, chars: &[Grapheme]) -> Result<usize, DigitCodeError> {
+        if start >= self.code.len() {
+            return Err(DigitCodeError::OutOfRange { index: start });
+        }
+        let chars = &chars[..chars.len().min(self.code.len() - start)];
+        let invalid = chars
+            .iter()
+            .enumerate()
+            .find(|&(offset, chr)| !self.profile.is_valid_char_at(start + offset, chr));
+        if let Some((offset, chr)) = invalid {
+            return Err(DigitCodeError::InvalidChar {
+                index: start + offset,
+                value: chr.to_string(),
+            });
+        }
+        for (offset, chr) in chars.iter().enumerate() {
+            self.code[start + offset] = Some(chr.clone());
+        }
+        Ok(start + chars.len())
+    }
+    /// Returns the first empty digit at or after `start`, wrapping around to the beginning.
+    pub fn first_empty_from(&self, start: usize) -> Option<usize> {
+        let start = start.min(self.code.len());
+        (start..self.code.len())
+            .chain(0..start)
+            .find(|index| self.code[*index].is_none())
     }
+    /// The profile of the code
     pub fn profile(&self) -> Arc<PROFILE> {
         self.profile.clone()
     }
+    /// The amount of digits, the maximum if the code has a variable length
+    #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
         self.profile.len()
     }
-    pub fn get(&self, index: usize) -> &Option<ArcStrOrChar> {
+    /// The minimum amount of digits of a valid code, see `DigitCodeProfile::min_len`
+    pub fn min_len(&self) -> usize {
+        self.profile.min_len().min(self.code.len())
+    }
+    /// Checks if the digit at `index` is an optional trailing digit that isn't used.
+    ///
+    /// These are the empty digits after the minimum length that are followed by empty digits only.
+    pub fn is_unused(&self, index: usize) -> bool {
+        index >= self.min_len()
+            && self
+                .code
+                .get(index..)
+                .is_some_and(|rest| rest.iter().all(Option::is_none))
+    }
+    /// The digit at `index`, `None` if it's empty or out of range
+    pub fn get(&self, index: usize) -> &Option<Grapheme> {
         self.code.get(index).unwrap_or(&None)
     }
+    /// Removes all digits
     pub fn clear(&mut self) {
         for val in self.code.iter_mut() {
             *val = None;
         }
     }
+    /// A copy of this code without digits
     pub fn as_empty(&self) -> Self {
         let mut val = self.clone();
         val.clear();
         val
     }
-    #[allow(unused)]
-    pub fn with_set(&self, index: usize, value: impl Into<Option<ArcStrOrChar>>) -> Self {
+    /// A copy of this code with the digit at `index` set like `set` does
+    pub fn with_set(&self, index: usize, value: impl Into<Option<Grapheme>>) -> Self {
         let mut val = self.clone();
-        val.set(index, value.into());
+        let _ = val.set(index, value.into());
         val
     }
-    #[allow(unused)]
-    pub fn iter(&self) -> impl Iterator<Item = &Option<ArcStrOrChar>> {
+    /// Iterates over all digits
+    pub fn iter(&self) -> impl Iterator<Item = &Option<Grapheme>> {
         self.code.iter()
     }
-    pub fn iter_some(&self) -> impl Iterator<Item = &ArcStrOrChar> {
+    /// Iterates over all digits that aren't empty
+    pub fn iter_some(&self) -> impl Iterator<Item = &Grapheme> {
         self.code.iter().flatten()
     }
 
+    /// The code as text if it's complete and valid
     pub fn joined(&self) -> Option<String> {
         if self.is_valid() {
             #[cfg(feature = "itertools")]
@@ -92,15 +174,43 @@ impl<PROFILE: DigitCodeProfile + 'static> DigitCode<PROFILE> {
         }
     }
 
+    /// A snapshot of the digits that doesn't depend on the profile
+    pub fn partial(&self) -> PartialCode {
+        PartialCode {
+            digits: self
+                .code
+                .iter()
+                .map(|digit| digit.as_ref().map(|s| s.to_string()))
+                .collect(),
+            complete: self.is_valid(),
+        }
+    }
+
+    /// Checks if every digit is filled, the code could still fail `DigitCodeProfile::validate_code`
+    pub fn is_complete(&self) -> bool {
+        self.code.iter().all(Option::is_some)
+    }
+    /// Checks if the code is complete and valid for the profile
     pub fn is_valid(&self) -> bool {
-        #[cfg(feature = "unicode-segmentation")]
-        let chars = self.code.iter().flatten().map(|o| {
-            let o: &str = o;
-            o
-        });
-        #[cfg(not(feature = "unicode-segmentation"))]
-        let chars = self.code.iter().flatten().cloned();
-        self.profile.is_char_code_valid(chars)
+        self.validate().is_ok()
+    }
+    /// Returns the code as text if it's complete and valid for the profile, otherwise the reason why it isn't
+    ///
+    /// For codes with a variable length the first `min_len` digits and every digit before the last filled one are needed.
+    pub fn validate(&self) -> Result<String, DigitCodeError> {
+        let filled = self
+            .code
+            .iter()
+            .rposition(Option::is_some)
+            .map_or(0, |last| last + 1);
+        let missing: Vec<_> = (0..filled.max(self.min_len()))
+            .filter(|index| self.code[*index].is_none())
+            .collect();
+        if !missing.is_empty() {
+            return Err(DigitCodeError::Incomplete { missing });
+        }
+        let chars = self.code[..filled].iter().flatten().map(Grapheme::as_str);
+        self.profile.check_char_code(chars)
     }
 
     pub(crate) fn change_update_indicator(&mut self) {
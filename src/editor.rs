@@ -0,0 +1,569 @@
+use std::sync::Arc;
+
+use crate::focus_offset::{FocusOffset, FocusResult};
+use crate::{DigitCode, DigitCodeError, DigitCodeProfile, Grapheme};
+
+/// An input event for the `DigitCodeEditor`.
+///
+/// `index` is always the index of the digit the event occurred on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditorEvent {
+    /// A key was pressed. The name is the one of the `key` attribute of a html keyboard event.
+    ///
+    /// Special keys get forwarded to `Backspace`, `Arrow` and `Enter`.
+    KeyDown { index: usize, key: String },
+    /// The text of a digit changed. More than one char is handled like `Paste`
+    Input { index: usize, value: String },
+    /// A text was pasted into a digit.
+    ///
+    /// A whole code (as many chars as the code has digits) replaces all digits, wherever it's pasted.
+    /// Shorter texts are written from `index` on. Texts that don't fit behind `index` are rejected
+    /// with `DigitCodeError::OutOfRange`, so digits that were entered before aren't overwritten.
+    Paste { index: usize, text: String },
+    /// Removes the digit and moves to the previous one
+    Backspace { index: usize },
+    /// Moves to the neighbouring digit
+    Arrow { index: usize, offset: FocusOffset },
+    /// Submits the code if it's complete, codes with a variable length need at least `min_len` digits
+    Enter { index: usize },
+    /// Removes all digits
+    Clear,
+    /// Replaces all digits with the text. Separators get dropped like with `Paste`.
+    ///
+    /// The text may be shorter than the code, nothing changes if it contains invalid chars.
+    SetValue(String),
+    /// Sets or removes a single digit without moving the focus
+    SetDigit { index: usize, value: Option<String> },
+    /// Replaces every digit, empty digits stay empty. Each value is checked like with `SetDigit`.
+    ///
+    /// Missing values are empty, nothing changes if a value is invalid or there are more values than digits.
+    /// No effect is returned if the digits are already equal.
+    SetDigits(Vec<Option<String>>),
+    /// Moves to the given digit
+    FocusIndex(usize),
+    /// Moves to the first empty digit, or the last digit if every digit is filled
+    FocusFirstEmpty,
+    /// Moves to the last empty digit, or the last digit if every digit is filled
+    FocusLastEmpty,
+    /// Selects every digit and moves to the first one.
+    ///
+    /// The next typed char, `Backspace` or pasted text replaces the whole code, see `DigitCodeEditor::all_selected`.
+    SelectAll,
+}
+
+/// An action the user of a `DigitCodeEditor` needs to perform after an event.
+///
+/// Effects are returned in the order they should be applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditorEffect {
+    /// At least one digit changed. The new state is available with `DigitCodeEditor::code`
+    ValueChanged,
+    /// The digit with this index should get the focus
+    Focus(usize),
+    /// The complete code should be submitted
+    Submit(String),
+    /// The input was rejected, e.g. an invalid char was typed or the code failed `DigitCodeProfile::validate_code`
+    ///
+    /// Incomplete codes are only reported if they are submitted with `Enter`.
+    Invalid(DigitCodeError),
+}
+
+/// Framework independent state machine containing the editing logic of the digit code component.
+///
+/// It processes `EditorEvent`s and returns the `EditorEffect`s the caller has to apply,
+/// like moving the focus or submitting the code.
+///
+/// # Examples
+///
+/// ```
+/// use yew_digit_code::{DigitCodeEditor, EditorEffect, EditorEvent, TotpCodeProfile};
+///
+/// let mut editor = DigitCodeEditor::<TotpCodeProfile<4>>::default();
+///
+/// let effects = editor.handle(EditorEvent::Input { index: 0, value: "1".into() });
+/// assert_eq!(effects, vec![EditorEffect::ValueChanged, EditorEffect::Focus(1)]);
+///
+/// let effects = editor.handle(EditorEvent::Paste { index: 1, text: "23 4".into() });
+/// assert_eq!(
+///     effects,
+///     vec![
+///         EditorEffect::ValueChanged,
+///         EditorEffect::Focus(3),
+///         EditorEffect::Submit("1234".into())
+///     ]
+/// );
+/// assert_eq!(editor.code().joined(), Some("1234".to_string()));
+/// ```
+///
+/// A whole code can be pasted into any digit, shorter texts that don't fit behind the digit are rejected
+/// ```
+/// use yew_digit_code::{DigitCodeEditor, DigitCodeError, EditorEffect, EditorEvent, TotpCodeProfile};
+///
+/// let mut editor = DigitCodeEditor::<TotpCodeProfile<6>>::default();
+///
+/// let effects = editor.handle(EditorEvent::Paste { index: 3, text: "123456".into() });
+/// assert_eq!(
+///     effects,
+///     vec![
+///         EditorEffect::ValueChanged,
+///         EditorEffect::Focus(5),
+///         EditorEffect::Submit("123456".into())
+///     ]
+/// );
+///
+/// editor.handle(EditorEvent::Clear);
+/// editor.handle(EditorEvent::Paste { index: 0, text: "123".into() });
+/// let effects = editor.handle(EditorEvent::Paste { index: 5, text: "456".into() });
+/// assert_eq!(effects, vec![EditorEffect::Invalid(DigitCodeError::OutOfRange { index: 6 })]);
+/// assert_eq!(editor.code().partial().digits[..4], [Some("1".into()), Some("2".into()), Some("3".into()), None]);
+///
+/// let effects = editor.handle(EditorEvent::Paste { index: 0, text: "1234567".into() });
+/// assert_eq!(effects, vec![EditorEffect::Invalid(DigitCodeError::OutOfRange { index: 6 })]);
+/// ```
+///
+/// Invalid chars are rejected and the focus stays on the digit
+/// ```
+/// use yew_digit_code::{
+///     DigitCodeEditor, DigitCodeError, EditorEffect, EditorEvent, FocusOffset, TotpCodeProfile,
+/// };
+///
+/// let mut editor = DigitCodeEditor::<TotpCodeProfile<4>>::default();
+///
+/// let effects = editor.handle(EditorEvent::Input { index: 2, value: "x".into() });
+/// assert_eq!(
+///     effects,
+///     vec![
+///         EditorEffect::ValueChanged,
+///         EditorEffect::Invalid(DigitCodeError::InvalidChar { index: 2, value: "x".into() })
+///     ]
+/// );
+/// assert_eq!(editor.code().get(2), &None);
+///
+/// let effects = editor.handle(EditorEvent::KeyDown { index: 2, key: "ArrowLeft".into() });
+/// assert_eq!(effects, vec![EditorEffect::Focus(1)]);
+///
+/// let effects = editor.handle(EditorEvent::Arrow { index: 3, offset: FocusOffset::Next });
+/// assert!(effects.is_empty());
+///
+/// // incomplete codes aren't submitted
+/// assert_eq!(
+///     editor.handle(EditorEvent::Enter { index: 3 }),
+///     vec![EditorEffect::Invalid(DigitCodeError::Incomplete { missing: vec![0, 1, 2, 3] })]
+/// );
+///
+/// let effects = editor.handle(EditorEvent::SetValue("12".into()));
+/// assert_eq!(effects, vec![EditorEffect::ValueChanged]);
+/// let partial = editor.code().partial();
+/// assert_eq!(partial.digits, vec![Some("1".into()), Some("2".into()), None, None]);
+/// assert!(!partial.complete);
+///
+/// let effects = editor.handle(EditorEvent::SetDigit { index: 3, value: Some("4".into()) });
+/// assert_eq!(effects, vec![EditorEffect::ValueChanged]);
+/// assert_eq!(editor.handle(EditorEvent::FocusFirstEmpty), vec![EditorEffect::Focus(2)]);
+///
+/// // gaps are kept
+/// let digits = vec![Some("1".into()), None, Some("3".into()), None];
+/// let effects = editor.handle(EditorEvent::SetDigits(digits.clone()));
+/// assert_eq!(effects, vec![EditorEffect::ValueChanged]);
+/// assert_eq!(editor.code().partial().digits, digits);
+/// assert!(editor.handle(EditorEvent::SetDigits(digits)).is_empty());
+/// ```
+///
+/// Codes with a variable length can be submitted with `Enter` once the minimum is reached
+/// ```
+/// use std::sync::Arc;
+/// use yew_digit_code::{DigitCodeEditor, DigitCodeError, DynamicCodeProfile, EditorEffect, EditorEvent};
+///
+/// let profile = DynamicCodeProfile::new(8, "0123456789").min_length(4);
+/// let mut editor = DigitCodeEditor::new(Arc::new(profile));
+///
+/// editor.handle(EditorEvent::Paste { index: 0, text: "123".into() });
+/// assert_eq!(
+///     editor.handle(EditorEvent::Enter { index: 3 }),
+///     vec![EditorEffect::Invalid(DigitCodeError::Incomplete { missing: vec![3] })]
+/// );
+/// let effects = editor.handle(EditorEvent::Input { index: 3, value: "4".into() });
+/// assert_eq!(effects, vec![EditorEffect::ValueChanged, EditorEffect::Focus(4)]);
+/// assert!(editor.code().is_unused(4));
+/// assert_eq!(
+///     editor.handle(EditorEvent::Enter { index: 4 }),
+///     vec![EditorEffect::Submit("1234".into())]
+/// );
+///
+/// // it's submitted automatically once all digits are filled
+/// let effects = editor.handle(EditorEvent::Paste { index: 4, text: "5678".into() });
+/// assert_eq!(effects.last(), Some(&EditorEffect::Submit("12345678".into())));
+/// ```
+///
+/// After `SelectAll` the next char replaces the whole code, other keys like `Shift` keep the selection
+/// ```
+/// use yew_digit_code::{DigitCodeEditor, EditorEffect, EditorEvent, TotpCodeProfile};
+///
+/// let mut editor = DigitCodeEditor::<TotpCodeProfile<4>>::default();
+/// editor.handle(EditorEvent::SetValue("1234".into()));
+///
+/// assert_eq!(editor.handle(EditorEvent::SelectAll), vec![EditorEffect::Focus(0)]);
+/// assert!(editor.all_selected());
+/// assert!(editor.handle(EditorEvent::KeyDown { index: 0, key: "Shift".into() }).is_empty());
+/// let effects = editor.handle(EditorEvent::KeyDown { index: 0, key: "5".into() });
+/// assert_eq!(effects, vec![EditorEffect::ValueChanged, EditorEffect::Focus(1)]);
+/// assert_eq!(editor.code().partial().digits, vec![Some("5".into()), None, None, None]);
+/// assert!(!editor.all_selected());
+///
+/// editor.handle(EditorEvent::SetValue("1234".into()));
+/// editor.handle(EditorEvent::SelectAll);
+/// let effects = editor.handle(EditorEvent::KeyDown { index: 0, key: "Backspace".into() });
+/// assert_eq!(effects, vec![EditorEffect::ValueChanged, EditorEffect::Focus(0)]);
+/// assert_eq!(editor.code().partial().digits, vec![None; 4]);
+///
+/// // any other event ends the selection
+/// editor.handle(EditorEvent::SetValue("1234".into()));
+/// editor.handle(EditorEvent::SelectAll);
+/// editor.handle(EditorEvent::KeyDown { index: 0, key: "ArrowRight".into() });
+/// assert!(!editor.all_selected());
+/// editor.handle(EditorEvent::KeyDown { index: 1, key: "Backspace".into() });
+/// assert_eq!(editor.code().partial().digits, vec![Some("1".into()), None, Some("3".into()), Some("4".into())]);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DigitCodeEditor<PROFILE: DigitCodeProfile + 'static> {
+    code: DigitCode<PROFILE>,
+    all_selected: bool,
+}
+
+impl<PROFILE: DigitCodeProfile + 'static> Default for DigitCodeEditor<PROFILE> {
+    fn default() -> Self {
+        Self::from(DigitCode::default())
+    }
+}
+
+impl<PROFILE: DigitCodeProfile + 'static> From<DigitCode<PROFILE>> for DigitCodeEditor<PROFILE> {
+    fn from(code: DigitCode<PROFILE>) -> Self {
+        Self {
+            code,
+            all_selected: false,
+        }
+    }
+}
+
+impl<PROFILE: DigitCodeProfile + 'static> DigitCodeEditor<PROFILE> {
+    /// Creates an editor with an empty code for the profile
+    pub fn new(profile: Arc<PROFILE>) -> Self {
+        Self::from(DigitCode::new(profile))
+    }
+    /// The current state of the code
+    pub fn code(&self) -> &DigitCode<PROFILE> {
+        &self.code
+    }
+    /// Returns the code and consumes the editor
+    pub fn into_code(self) -> DigitCode<PROFILE> {
+        self.code
+    }
+    /// Checks if every digit is selected, so the next char, `Backspace` or paste replaces the whole code.
+    ///
+    /// The selection is started with `EditorEvent::SelectAll` and ends with the next event,
+    /// only keys without an action (e.g. `Shift`) keep it.
+    pub fn all_selected(&self) -> bool {
+        self.all_selected
+    }
+    /// Restores the selection of an editor that was created from its code
+    pub fn with_all_selected(mut self, all_selected: bool) -> Self {
+        self.all_selected = all_selected && self.code.len() > 0;
+        self
+    }
+
+    /// Processes an event and returns the effects that need to be applied
+    pub fn handle(&mut self, event: EditorEvent) -> Vec<EditorEffect> {
+        #[cfg(feature = "log")]
+        log::trace!("Editor handles {event:?}");
+        if self.all_selected {
+            if let Some(effects) = self.replace_selection(&event) {
+                self.all_selected = false;
+                return effects;
+            }
+            self.all_selected =
+                matches!(&event, EditorEvent::KeyDown { key, .. } if keeps_selection(key));
+        }
+        match event {
+            EditorEvent::KeyDown { index, key } => self.key_down(index, &key),
+            EditorEvent::Input { index, value } => self.input(index, &value),
+            EditorEvent::Paste { index, text } => self.paste(index, &text),
+            EditorEvent::Backspace { index } => self.backspace(index),
+            EditorEvent::Arrow { index, offset } => self.focus_offset(index, offset),
+            EditorEvent::Enter { index: _ } => vec![self.enter()],
+            EditorEvent::Clear => {
+                self.code.clear();
+                self.changed()
+            }
+            EditorEvent::SetValue(text) => self.set_value(&text),
+            EditorEvent::SetDigit { index, value } => self.set_digit(index, value.as_deref()),
+            EditorEvent::SetDigits(digits) => self.set_digits(&digits),
+            EditorEvent::FocusIndex(index) => self.focus(index).into_iter().collect(),
+            EditorEvent::FocusFirstEmpty => {
+                let empty = self.code.first_empty_from(0);
+                self.focus_empty(empty)
+            }
+            EditorEvent::FocusLastEmpty => {
+                let empty = (0..self.code.len()).rfind(|index| self.code.get(*index).is_none());
+                self.focus_empty(empty)
+            }
+            EditorEvent::SelectAll => {
+                self.all_selected = self.code.len() > 0;
+                self.focus(0).into_iter().collect()
+            }
+        }
+    }
+
+    /// Replaces the whole code if the event edits a selected code, `None` if it's handled like usual
+    fn replace_selection(&mut self, event: &EditorEvent) -> Option<Vec<EditorEffect>> {
+        let text = match event {
+            EditorEvent::Backspace { .. } => "",
+            EditorEvent::KeyDown { key, .. } if key == "Backspace" => "",
+            EditorEvent::KeyDown { key, .. } => single_char(key)?,
+            EditorEvent::Input { value, .. } => value,
+            EditorEvent::Paste { text, .. } => text,
+            _ => return None,
+        };
+        let mut editor = Self::from(self.code.as_empty());
+        let effects = if text.is_empty() {
+            let mut effects = editor.changed();
+            effects.extend(editor.focus(0));
+            effects
+        } else {
+            editor.paste(0, text)
+        };
+        // the browser already inserted the char, so the digit needs to be corrected like any other input
+        let rejected_input = matches!(event, EditorEvent::Input { .. })
+            && !effects.contains(&EditorEffect::ValueChanged);
+        if rejected_input {
+            return None;
+        }
+        if effects.contains(&EditorEffect::ValueChanged) {
+            self.code = editor.code;
+        }
+        Some(effects)
+    }
+
+    fn key_down(&mut self, index: usize, key: &str) -> Vec<EditorEffect> {
+        match key {
+            "ArrowLeft" => self.focus_offset(index, FocusOffset::Previous),
+            "ArrowRight" => self.focus_offset(index, FocusOffset::Next),
+            "Enter" => self.handle(EditorEvent::Enter { index }),
+            "Backspace" => self.backspace(index),
+            key => {
+                // the old char gets removed so the browser can insert the new one
+                let profile = self.code.profile();
+                let valid = single_char(key).is_some_and(|chr| {
+                    profile.is_valid_char_at(index, &profile.normalize_char(chr))
+                });
+                if valid && self.code.set(index, None).is_ok() {
+                    self.changed()
+                } else {
+                    vec![]
+                }
+            }
+        }
+    }
+
+    fn input(&mut self, index: usize, value: &str) -> Vec<EditorEffect> {
+        if Grapheme::split(value).nth(1).is_some() {
+            return self.paste(index, value);
+        }
+
+        let profile = self.code.profile();
+        let chr = single_char(value).map(|chr| profile.normalize_char(chr));
+        let error = match self.code.set(index, chr) {
+            Ok(()) => None,
+            // the rejected char gets removed
+            Err(error @ DigitCodeError::InvalidChar { .. }) => {
+                let _ = self.code.set(index, None);
+                Some(error)
+            }
+            Err(_) => return vec![],
+        };
+        let mut effects = self.changed();
+        if let Some(error) = error {
+            effects.push(EditorEffect::Invalid(error));
+        } else if self.code.get(index).is_some() {
+            effects.extend(self.focus_offset(index, FocusOffset::Next));
+            if index == self.code.len() - 1 {
+                effects.extend(self.submit());
+            }
+        }
+        effects
+    }
+
+    fn paste(&mut self, index: usize, text: &str) -> Vec<EditorEffect> {
+        let profile = self.code.profile();
+        let chars = profile.normalize_code(text);
+        if chars.is_empty() {
+            return vec![];
+        }
+        let len = self.code.len();
+        let whole_code = chars.len() == len;
+        if !whole_code && index + chars.len() > len {
+            #[cfg(feature = "log")]
+            log::debug!("{index} ignored pasted text that doesn't fit behind the digit: {text:?}");
+            return vec![EditorEffect::Invalid(DigitCodeError::OutOfRange {
+                index: len,
+            })];
+        }
+        let (mut code, start) = if whole_code {
+            (self.code.as_empty(), 0)
+        } else {
+            (self.code.clone(), index)
+        };
+        let end = match code.fill(start, &chars) {
+            Ok(end) => end,
+            Err(error) => {
+                #[cfg(feature = "log")]
+                log::debug!("{index} ignored pasted text that doesn't fit the profile: {text:?}");
+                return vec![EditorEffect::Invalid(error)];
+            }
+        };
+        self.code = code;
+        let mut effects = self.changed();
+        if self.code.is_complete() {
+            effects.extend(self.focus(self.code.len() - 1));
+            effects.extend(self.submit());
+        } else if let Some(next) = self.code.first_empty_from(end) {
+            effects.extend(self.focus(next));
+        }
+        effects
+    }
+
+    fn set_value(&mut self, text: &str) -> Vec<EditorEffect> {
+        let profile = self.code.profile();
+        let chars = profile.normalize_code(text);
+        let mut code = self.code.as_empty();
+        if !chars.is_empty() {
+            if let Err(error) = code.fill(0, &chars) {
+                #[cfg(feature = "log")]
+                log::debug!("Ignored value that doesn't fit the profile: {text:?}");
+                return vec![EditorEffect::Invalid(error)];
+            }
+        }
+        self.code = code;
+        self.changed()
+    }
+
+    fn set_digit(&mut self, index: usize, value: Option<&str>) -> Vec<EditorEffect> {
+        let result = self.digit_char(index, value);
+        match result.and_then(|chr| self.code.set(index, chr)) {
+            Ok(()) => self.changed(),
+            Err(error) => vec![EditorEffect::Invalid(error)],
+        }
+    }
+
+    fn set_digits(&mut self, digits: &[Option<String>]) -> Vec<EditorEffect> {
+        let len = self.code.len();
+        if digits.len() > len {
+            return vec![EditorEffect::Invalid(DigitCodeError::OutOfRange {
+                index: len,
+            })];
+        }
+        let mut code = self.code.as_empty();
+        for (index, value) in digits.iter().enumerate() {
+            let result = self.digit_char(index, value.as_deref());
+            if let Err(error) = result.and_then(|chr| code.set(index, chr)) {
+                return vec![EditorEffect::Invalid(error)];
+            }
+        }
+        if code.partial().digits == self.code.partial().digits {
+            return vec![];
+        }
+        self.code = code;
+        self.changed()
+    }
+
+    /// The normalized char of a digit, the text must be a single char
+    fn digit_char(
+        &self,
+        index: usize,
+        value: Option<&str>,
+    ) -> Result<Option<Grapheme>, DigitCodeError> {
+        let Some(text) = value else {
+            return Ok(None);
+        };
+        match single_char(text) {
+            Some(chr) => Ok(Some(self.code.profile().normalize_char(chr))),
+            None => Err(DigitCodeError::InvalidChar {
+                index,
+                value: text.to_owned(),
+            }),
+        }
+    }
+
+    fn backspace(&mut self, index: usize) -> Vec<EditorEffect> {
+        if self.code.set(index, None).is_err() {
+            return vec![];
+        }
+        let mut effects = self.changed();
+        effects.extend(self.focus_offset(index, FocusOffset::Previous));
+        effects
+    }
+
+    fn changed(&mut self) -> Vec<EditorEffect> {
+        self.code.change_update_indicator();
+        vec![EditorEffect::ValueChanged]
+    }
+
+    /// Submits the code or reports why it can't be submitted
+    fn enter(&self) -> EditorEffect {
+        match self.code.validate() {
+            Ok(code) => EditorEffect::Submit(code),
+            Err(error) => EditorEffect::Invalid(error),
+        }
+    }
+
+    /// Submits the code automatically once every digit is filled
+    fn submit(&self) -> Option<EditorEffect> {
+        self.code.is_complete().then(|| self.enter())
+    }
+
+    fn focus(&self, index: usize) -> Option<EditorEffect> {
+        (index < self.code.len()).then_some(EditorEffect::Focus(index))
+    }
+
+    fn focus_empty(&self, empty: Option<usize>) -> Vec<EditorEffect> {
+        let index = empty.or(self.code.len().checked_sub(1));
+        index
+            .and_then(|index| self.focus(index))
+            .into_iter()
+            .collect()
+    }
+
+    fn focus_offset(&self, index: usize, offset: FocusOffset) -> Vec<EditorEffect> {
+        if self.code.len() == 0 {
+            return vec![];
+        }
+        match offset.process(index, self.code.len() - 1) {
+            FocusResult::Ok(index) => vec![EditorEffect::Focus(index)],
+            _ => vec![],
+        }
+    }
+}
+
+/// Keys without an action that are pressed while typing, e.g. to enter uppercase letters
+fn keeps_selection(key: &str) -> bool {
+    matches!(
+        key,
+        "Shift"
+            | "Control"
+            | "Alt"
+            | "AltGraph"
+            | "Meta"
+            | "CapsLock"
+            | "Dead"
+            | "Process"
+            | "Unidentified"
+    )
+}
+
+/// Returns the text as char if it consists of exactly one
+fn single_char(text: &str) -> Option<&str> {
+    let mut chars = Grapheme::split(text);
+    match (chars.next(), chars.next()) {
+        (Some(chr), None) => Some(chr),
+        _ => None,
+    }
+}
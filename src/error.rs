@@ -0,0 +1,59 @@
+use std::fmt::{Display, Formatter};
+
+/// Describes why a digit or a code was rejected
+///
+/// It's returned by the checking methods of `DigitCode` and `DigitCodeProfile` and delivered to
+/// the `oninvalid` callback of `CodeDigitInput`.
+///
+/// # Examples
+///
+/// ```
+/// use yew_digit_code::{DigitCode, DigitCodeError, TotpCodeProfile};
+///
+/// let mut code = DigitCode::<TotpCodeProfile<4>>::default();
+/// assert_eq!(code.set(4, None), Err(DigitCodeError::OutOfRange { index: 4 }));
+/// assert_eq!(
+///     code.set(1, Some("x".into())),
+///     Err(DigitCodeError::InvalidChar { index: 1, value: "x".into() })
+/// );
+/// code.set(1, Some("7".into())).unwrap();
+/// assert_eq!(code.validate(), Err(DigitCodeError::Incomplete { missing: vec![0, 2, 3] }));
+/// assert_eq!(
+///     DigitCodeError::Incomplete { missing: vec![0, 2, 3] }.to_string(),
+///     "the digits 0, 2, 3 are missing"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DigitCodeError {
+    /// The index is outside of the code, or the text doesn't fit into the digits behind the index
+    OutOfRange { index: usize },
+    /// The char isn't allowed at this position
+    InvalidChar { index: usize, value: String },
+    /// The digits with these indices are empty
+    Incomplete { missing: Vec<usize> },
+    /// Every char is valid but the code fails `DigitCodeProfile::validate_code`, e.g. its check digit
+    ChecksumFailed { code: String },
+}
+
+impl Display for DigitCodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DigitCodeError::OutOfRange { index } => {
+                write!(f, "the index {index} is out of range")
+            }
+            DigitCodeError::InvalidChar { index, value } => {
+                write!(f, "{value:?} isn't allowed at index {index}")
+            }
+            DigitCodeError::Incomplete { missing } => {
+                let missing: Vec<_> = missing.iter().map(usize::to_string).collect();
+                write!(f, "the digits {} are missing", missing.join(", "))
+            }
+            DigitCodeError::ChecksumFailed { code } => {
+                write!(f, "the code {code:?} failed the validation")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DigitCodeError {}
@@ -1,7 +1,9 @@
 //! Some additional exports that are not needed for usage.
 
+pub use crate::boxed_profile::DynDigitCodeProfile;
 pub use crate::control_flags::ControlFlagsBuilder;
-/// This is an internal type alias that is set dependent on the `unicode-segmentation` feature being present
+#[allow(deprecated)]
 pub use crate::digit_code_status::ArcStrOrChar;
-/// This is an internal type alias that is set dependent on the `unicode-segmentation` feature being present
+pub use crate::predefined::{CharClass, MaskError};
+#[allow(deprecated)]
 pub use crate::profile::RefStrOrChar;
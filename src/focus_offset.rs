@@ -1,13 +1,13 @@
-use wasm_bindgen::JsCast;
-use web_sys::{window, Document, HtmlInputElement};
+use web_sys::HtmlInputElement;
+use yew::NodeRef;
 
-pub fn document() -> Option<Document> {
-    window()?.document()
-}
-
-#[derive(Debug, Clone)]
+/// Direction to move the focus to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FocusOffset {
+    /// The digit on the right
     Next,
+    /// The digit on the left
     Previous,
 }
 
@@ -16,14 +16,13 @@ pub enum FocusResult<T = ()> {
     Ok(T),
     TooBig,
     TooLow,
-    NoDocument,
 }
 
 impl FocusOffset {
     /// Adds or subtracts 1 to the current index
     ///
     /// maximum is the last index that exists
-    pub fn process(&self, current: usize, maximum: usize) -> FocusResult<usize> {
+    pub(crate) fn process(&self, current: usize, maximum: usize) -> FocusResult<usize> {
         match self {
             FocusOffset::Next => {
                 if current == maximum {
@@ -43,42 +42,18 @@ impl FocusOffset {
     }
 }
 
-pub fn focus_offset(
-    id: String,
-    element_count: usize,
-    offset: FocusOffset,
-) -> impl Fn(usize) -> FocusResult<()> {
-    move |i: usize| {
-        let index = offset.process(i, element_count - 1);
-
+/// Focusses the input of a digit, with `select` its content gets selected as well
+///
+/// Returns `false` if the reference isn't attached to an input at the moment.
+pub(crate) fn focus_node(node: &NodeRef, select: bool) -> bool {
+    let Some(input) = node.cast::<HtmlInputElement>() else {
         #[cfg(feature = "log")]
-        log::trace!("Focus offset is called: {offset:?}, index={i} total={element_count}");
-
-        let index = match index {
-            FocusResult::Ok(index) => index,
-            FocusResult::TooBig => return FocusResult::TooBig,
-            FocusResult::TooLow => return FocusResult::TooLow,
-            // can't occur at this point
-            FocusResult::NoDocument => return FocusResult::NoDocument,
-        };
-
-        if let Some(document) = document() {
-            let node = document.query_selector(&format!("#{} input[data-index=\"{}\"]", id, index));
-            if let Ok(Some(node)) = node {
-                let node = node.dyn_into::<HtmlInputElement>().ok();
-                if let Some(node) = node {
-                    let _ = node.focus();
-                }
-            } else {
-                #[cfg(feature = "log")]
-                log::error!("An error occured while focussing a node: {node:?}");
-                return FocusResult::NoDocument;
-            }
-            FocusResult::Ok(())
-        } else {
-            #[cfg(feature = "log")]
-            log::error!("The focus method was called before a document was ready. Therefore the call will be ignored and this could lead to unexpected behaviour.");
-            FocusResult::NoDocument
-        }
+        log::debug!("Can't focus a digit that isn't rendered");
+        return false;
+    };
+    let _ = input.focus();
+    if select {
+        input.select();
     }
+    true
 }
@@ -0,0 +1,114 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Deref;
+use std::sync::Arc;
+
+#[cfg(feature = "unicode-segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The text of a single digit.
+///
+/// With the `unicode-segmentation` feature it's one extended grapheme cluster (e.g. `👍🏽`),
+/// otherwise one unicode scalar value. The API is the same in both cases, only the
+/// splitting of texts with `Grapheme::split` differs.
+///
+/// # Examples
+///
+/// ```
+/// use yew_digit_code::Grapheme;
+///
+/// let grapheme = Grapheme::from('7');
+/// assert_eq!(grapheme, "7");
+/// assert_eq!(grapheme.as_str(), "7");
+/// assert_eq!(grapheme.to_string(), "7");
+///
+/// assert_eq!(Grapheme::split("a-1").collect::<Vec<_>>(), vec!["a", "-", "1"]);
+/// assert!(Grapheme::is_single("x"));
+/// assert!(!Grapheme::is_single("xy"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "String", into = "String")
+)]
+pub struct Grapheme(Arc<str>);
+
+impl Grapheme {
+    /// The text of the grapheme
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Splits a text into graphemes (or chars without the `unicode-segmentation` feature)
+    pub fn split(text: &str) -> impl Iterator<Item = &str> {
+        #[cfg(feature = "unicode-segmentation")]
+        let graphemes = text.graphemes(true);
+        #[cfg(not(feature = "unicode-segmentation"))]
+        let graphemes = text
+            .char_indices()
+            .map(move |(start, chr)| &text[start..start + chr.len_utf8()]);
+        graphemes
+    }
+
+    /// Checks if the text consists of exactly one grapheme
+    pub fn is_single(text: &str) -> bool {
+        let mut graphemes = Self::split(text);
+        graphemes.next().is_some() && graphemes.next().is_none()
+    }
+}
+
+impl Deref for Grapheme {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Grapheme {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Grapheme {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for Grapheme {
+    fn from(text: &str) -> Self {
+        Self(text.into())
+    }
+}
+
+impl From<String> for Grapheme {
+    fn from(text: String) -> Self {
+        Self(text.into())
+    }
+}
+
+impl From<char> for Grapheme {
+    fn from(chr: char) -> Self {
+        Self(chr.to_string().into())
+    }
+}
+
+impl From<Grapheme> for String {
+    fn from(grapheme: Grapheme) -> Self {
+        grapheme.0.to_string()
+    }
+}
+
+impl PartialEq<str> for Grapheme {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Grapheme {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
@@ -0,0 +1,102 @@
+/// Describes how the digits of a code are split into visual groups, e.g. `123-456`
+///
+/// The sizes are used from left to right, the last size is repeated until all digits are
+/// part of a group. Without sizes all digits are in one group.
+///
+/// # Examples
+///
+/// ```
+/// use yew_digit_code::Grouping;
+///
+/// let grouping = Grouping::every(4, "-");
+/// assert_eq!(grouping.format(["A", "B", "C", "D", "E", "F", "G", "H"]), "ABCD-EFGH");
+/// assert!(grouping.separator_before(4));
+/// assert!(!grouping.separator_before(0));
+///
+/// let grouping = Grouping::new([2, 3], " ");
+/// assert_eq!(grouping.format("12345678".chars()), "12 345 678");
+/// assert_eq!(grouping.group_of(4), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grouping {
+    /// The number of digits in each group
+    pub sizes: Vec<usize>,
+    /// The text rendered between two groups
+    pub separator: String,
+}
+
+impl Grouping {
+    /// Creates a grouping with the given group sizes
+    pub fn new(sizes: impl IntoIterator<Item = usize>, separator: impl Into<String>) -> Self {
+        Self {
+            sizes: sizes.into_iter().filter(|&size| size > 0).collect(),
+            separator: separator.into(),
+        }
+    }
+    /// All digits are in one group
+    pub fn none() -> Self {
+        Self::default()
+    }
+    /// Groups of equal size
+    pub fn every(size: usize, separator: impl Into<String>) -> Self {
+        Self::new([size], separator)
+    }
+
+    /// Checks if the code is split into groups at all
+    pub fn is_grouped(&self) -> bool {
+        self.sizes.iter().any(|&size| size > 0)
+    }
+
+    /// The index of the group the digit at `index` belongs to.
+    ///
+    /// Sizes of 0 are skipped, they can only appear if `sizes` was set directly or deserialized.
+    ///
+    /// ```
+    /// use yew_digit_code::Grouping;
+    ///
+    /// let grouping = Grouping { sizes: vec![0], separator: "-".into() };
+    /// assert_eq!(grouping.group_of(1), 0);
+    /// assert!(!grouping.is_grouped());
+    ///
+    /// let grouping = Grouping { sizes: vec![2, 0], separator: "-".into() };
+    /// assert_eq!(grouping.group_of(5), 2);
+    /// ```
+    pub fn group_of(&self, index: usize) -> usize {
+        let mut start = 0;
+        let mut sizes = self.sizes.iter().copied().filter(|&size| size > 0);
+        let Some(mut size) = sizes.next() else {
+            return 0;
+        };
+        let mut group = 0;
+        loop {
+            if index < start + size {
+                return group;
+            }
+            start += size;
+            group += 1;
+            size = sizes.next().unwrap_or(size);
+        }
+    }
+
+    /// Checks if a separator is rendered in front of the digit at `index`
+    pub fn separator_before(&self, index: usize) -> bool {
+        index > 0 && self.group_of(index) != self.group_of(index - 1)
+    }
+
+    /// Joins the chars of a code and inserts the separators between the groups
+    pub fn format<I>(&self, chars: I) -> String
+    where
+        I: IntoIterator,
+        I::Item: std::fmt::Display,
+    {
+        let mut res = String::new();
+        for (index, chr) in chars.into_iter().enumerate() {
+            if self.separator_before(index) {
+                res.push_str(&self.separator);
+            }
+            res.push_str(&chr.to_string());
+        }
+        res
+    }
+}
@@ -0,0 +1,216 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+use yew::Callback;
+
+use crate::control_flags::FocusTarget;
+use crate::{DigitCodeError, DigitCodeProfile, PartialCode, UseDigitCodeHandle};
+
+/// A command for the component, sent with `DigitCodeHandle` or `ControlFlags`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Command {
+    Clear,
+    SetValue(String),
+    SetDigit(usize, Option<String>),
+    SetRevealed(bool),
+    SetDisabled(bool),
+    SetReadonly(bool),
+    MarkInvalid(DigitCodeError),
+    ShowError(String),
+    Focus(FocusTarget),
+    SelectAll,
+    Submit,
+}
+
+impl Command {
+    pub(crate) fn apply<PROFILE: DigitCodeProfile + 'static>(
+        self,
+        code: &UseDigitCodeHandle<PROFILE>,
+    ) {
+        match self {
+            Command::Clear => code.clear(),
+            Command::SetValue(text) => code.set_value(text),
+            Command::SetDigit(index, value) => code.set_digit(index, value),
+            Command::SetRevealed(revealed) => code.set_revealed(revealed),
+            Command::SetDisabled(disabled) => code.set_disabled(disabled),
+            Command::SetReadonly(readonly) => code.set_readonly(readonly),
+            Command::MarkInvalid(error) => code.mark_invalid(error),
+            Command::ShowError(message) => code.show_error(message),
+            Command::Focus(FocusTarget::Index(index)) => code.focus(index),
+            Command::Focus(FocusTarget::Last) => code.focus(code.len().saturating_sub(1)),
+            Command::Focus(FocusTarget::FirstEmpty) => code.focus_first_empty(),
+            Command::Focus(FocusTarget::LastEmpty) => code.focus_last_empty(),
+            Command::SelectAll => code.select_all(),
+            Command::Submit => code.submit(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Connection {
+    queue: VecDeque<Command>,
+    // rerenders the component so it processes the queue
+    notify: Option<Callback<()>>,
+    current: Option<Rc<dyn Fn() -> PartialCode>>,
+}
+
+/// Sends commands to a `CodeDigitInput` without `ControlFlags`.
+///
+/// Create one (e.g. with `use_memo`) and pass it as `handle` attribute or receive the handle of the component
+/// with the `onready` callback. It's cheap to clone, all clones control the same component.
+///
+/// Every method call is queued and processed in order after the next render of the component,
+/// so sending the same command twice executes it twice and commands sent before the component
+/// is mounted aren't lost. Focus and selection wait for another render, so e.g. `focus` after
+/// `set_disabled(false)` finds the digit enabled.
+///
+/// # Examples
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew_digit_code::{DigitCodeHandle, TotpInput};
+///
+/// #[function_component(CodeWithButtons)]
+/// fn code_with_buttons() -> Html {
+///     let handle = use_memo((), |_| DigitCodeHandle::new());
+///     let paste = {
+///         let handle = handle.clone();
+///         Callback::from(move |_| {
+///             handle.fill("123456");
+///             handle.submit();
+///         })
+///     };
+///     let reset = {
+///         let handle = handle.clone();
+///         Callback::from(move |_| {
+///             if let Some(code) = handle.current() {
+///                 log::info!("Reset {:?}", code.digits);
+///             }
+///             handle.clear();
+///             handle.focus(0);
+///         })
+///     };
+///
+///     html!(
+///         <>
+///             <TotpInput handle={(*handle).clone()}/>
+///             <button onclick={paste}>{"Use demo code"}</button>
+///             <button onclick={reset}>{"Reset"}</button>
+///         </>
+///     )
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct DigitCodeHandle {
+    connection: Rc<RefCell<Connection>>,
+}
+
+impl DigitCodeHandle {
+    /// Creates a handle that isn't connected to a component yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Focusses the digit at `index`
+    pub fn focus(&self, index: usize) {
+        self.send(Command::Focus(FocusTarget::Index(index)));
+    }
+    /// Focusses the first empty digit, or the last one if every digit is filled
+    pub fn focus_first_empty(&self) {
+        self.send(Command::Focus(FocusTarget::FirstEmpty));
+    }
+    /// Removes all digits
+    pub fn clear(&self) {
+        self.send(Command::Clear);
+    }
+    /// Replaces all digits with the text, see `EditorEvent::SetValue`. The code isn't submitted.
+    pub fn fill(&self, text: &str) {
+        self.send(Command::SetValue(text.to_owned()));
+    }
+    /// Sets or removes the digit at `index`
+    pub fn set_digit(&self, index: usize, value: Option<String>) {
+        self.send(Command::SetDigit(index, value));
+    }
+    /// Submits the code like `Enter` does, incomplete codes are marked as invalid
+    pub fn submit(&self) {
+        self.send(Command::Submit);
+    }
+    /// Selects every digit, so the next typed char or `Backspace` replaces the whole code
+    pub fn select_all(&self) {
+        self.send(Command::SelectAll);
+    }
+    /// Shows masked digits in clear text or masks them again
+    pub fn set_revealed(&self, revealed: bool) {
+        self.send(Command::SetRevealed(revealed));
+    }
+    /// Disables or enables all digits
+    pub fn set_disabled(&self, disabled: bool) {
+        self.send(Command::SetDisabled(disabled));
+    }
+    /// Makes all digits readonly or editable
+    pub fn set_readonly(&self, readonly: bool) {
+        self.send(Command::SetReadonly(readonly));
+    }
+    /// Shows the code as invalid (css class `invalid`) until the next edit
+    pub fn mark_invalid(&self, error: DigitCodeError) {
+        self.send(Command::MarkInvalid(error));
+    }
+    /// Shows the code as rejected (css class `error`) until the next edit
+    pub fn show_error(&self, message: impl Into<String>) {
+        self.send(Command::ShowError(message.into()));
+    }
+
+    /// The current digits, including changes that aren't rendered yet.
+    ///
+    /// It's `None` until the handle is connected to a component. Queued commands aren't applied yet.
+    pub fn current(&self) -> Option<PartialCode> {
+        let current = self.connection.borrow().current.clone();
+        current.map(|current| current())
+    }
+    /// Checks if the handle is connected to a mounted component
+    pub fn is_connected(&self) -> bool {
+        self.connection.borrow().notify.is_some()
+    }
+
+    pub(crate) fn send(&self, command: Command) {
+        let notify = {
+            let mut connection = self.connection.borrow_mut();
+            connection.queue.push_back(command);
+            connection.notify.clone()
+        };
+        if let Some(notify) = notify {
+            notify.emit(());
+        }
+    }
+    pub(crate) fn connect(&self, notify: Callback<()>, current: Rc<dyn Fn() -> PartialCode>) {
+        let mut connection = self.connection.borrow_mut();
+        connection.notify = Some(notify);
+        connection.current = Some(current);
+    }
+    pub(crate) fn disconnect(&self) {
+        let mut connection = self.connection.borrow_mut();
+        connection.notify = None;
+        connection.current = None;
+    }
+    pub(crate) fn take_commands(&self) -> Vec<Command> {
+        self.connection.borrow_mut().queue.drain(..).collect()
+    }
+}
+
+impl PartialEq for DigitCodeHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.connection, &other.connection)
+    }
+}
+
+impl Debug for DigitCodeHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let connection = self.connection.borrow();
+        f.debug_struct("DigitCodeHandle")
+            .field("queue", &connection.queue)
+            .field("connected", &connection.notify.is_some())
+            .finish()
+    }
+}
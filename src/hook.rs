@@ -0,0 +1,690 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{ClipboardEvent, EventTarget, HtmlInputElement};
+use yew::prelude::*;
+
+use crate::focus_offset::focus_node;
+use crate::{
+    DigitCode, DigitCodeEditor, DigitCodeError, DigitCodeProfile, EditorEffect, EditorEvent,
+    Grapheme, Masking, PartialCode, SubmitErrorPolicy, SubmitFuture, SubmitState,
+};
+
+/// Configuration of the `use_digit_code` hook
+///
+/// The digits are focussed with the `node_ref` of their `DigitHandlers`, so the `id` is optional.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DigitCodeConfig<PROFILE: DigitCodeProfile + 'static> {
+    /// Html id of the element containing the inputs, it's only used for log messages and the markup
+    pub id: Option<AttrValue>,
+    /// The profile of the code
+    pub profile: PROFILE,
+    /// Receives the code when the user submits it. If it's `None` the code gets logged.
+    pub submit_code: Option<Callback<String>>,
+    /// Receives the code instead of `submit_code`. The digits stay disabled until the future resolves.
+    pub submit_code_async: Option<Callback<String, SubmitFuture>>,
+    /// What happens if `submit_code_async` rejects the code
+    pub submit_error_policy: SubmitErrorPolicy,
+    /// Overrides the masking of the profile
+    pub masking: Option<Masking>,
+    /// A masked digit shows its char for this duration after it was typed
+    pub mask_peek: Option<Duration>,
+    /// The digits to show (controlled mode), only `PartialCode::digits` is used.
+    ///
+    /// It's compared with the code after every render, the digits get replaced if they differ.
+    /// Feed the code of `onchange` back to allow edits. A value that is rejected by the profile is ignored until it changes.
+    pub value: Option<PartialCode>,
+    /// Receives the digits after every change
+    pub onchange: Option<Callback<PartialCode>>,
+    /// Receives the reason whenever an input or a submitted code is rejected
+    pub oninvalid: Option<Callback<DigitCodeError>>,
+}
+
+impl<PROFILE: DigitCodeProfile + 'static> Default for DigitCodeConfig<PROFILE> {
+    fn default() -> Self {
+        Self {
+            id: None,
+            profile: PROFILE::default(),
+            submit_code: None,
+            submit_code_async: None,
+            submit_error_policy: SubmitErrorPolicy::default(),
+            masking: None,
+            mask_peek: None,
+            value: None,
+            onchange: None,
+            oninvalid: None,
+        }
+    }
+}
+
+impl<PROFILE: DigitCodeProfile + 'static> DigitCodeConfig<PROFILE> {
+    /// Creates a configuration with the default profile and an id
+    pub fn new(id: impl Into<AttrValue>) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// The state a single digit should be rendered with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigitState {
+    /// Index of the digit, `CodeDigitInput` renders it as `data-index` attribute
+    pub index: usize,
+    /// The text to display, the current char, a mask glyph or an empty string
+    pub value: String,
+    /// The html input type of the digit: `text` or `password`
+    pub input_type: String,
+    /// The html input mode of the digit
+    pub input_mode: String,
+    /// The digit shouldn't accept input at the moment
+    pub disabled: bool,
+    /// The digit can be focussed but not edited
+    pub readonly: bool,
+    /// The index of the visual group the digit belongs to
+    pub group: usize,
+    /// The separator that should be rendered in front of the digit, if it starts a new group
+    pub separator_before: Option<String>,
+    /// The digit is an optional trailing digit of a code with a variable length that isn't used, see `DigitCode::is_unused`
+    pub unused: bool,
+    /// Every digit is selected, see `DigitCodeEditor::all_selected`. `CodeDigitInput` renders it as class `selected`
+    pub selected: bool,
+}
+
+/// The event handlers of a single digit
+#[derive(Debug, Clone, PartialEq)]
+pub struct DigitHandlers {
+    /// Handler for `oninput`
+    pub oninput: Callback<InputEvent>,
+    /// Handler for `onkeydown`
+    pub onkeydown: Callback<KeyboardEvent>,
+    /// Handler for `onpaste`
+    pub onpaste: Callback<Event>,
+    /// Handler for `onclick`, it ends the selection of all digits
+    pub onclick: Callback<MouseEvent>,
+    /// Reference to the input of the digit, it needs to be set as `ref` so the digit can be focussed
+    pub node_ref: NodeRef,
+}
+
+/// Handle returned by `use_digit_code`
+///
+/// It provides the state and event handlers of every digit and actions to change the code.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UseDigitCodeHandle<PROFILE: DigitCodeProfile + 'static> {
+    id: Option<AttrValue>,
+    code: UseStateHandle<DigitCode<PROFILE>>,
+    disabled: UseStateHandle<bool>,
+    // disabled with `set_disabled`, independent of pending submissions
+    disabled_by_user: UseStateHandle<bool>,
+    readonly: UseStateHandle<bool>,
+    submit_state: UseStateHandle<SubmitState>,
+    submit_error_policy: SubmitErrorPolicy,
+    masking: Masking,
+    revealed: UseStateHandle<bool>,
+    peek: UseStateHandle<Option<usize>>,
+    all_selected: UseStateHandle<bool>,
+    node_refs: Rc<Vec<NodeRef>>,
+    latest_code: Rc<RefCell<DigitCode<PROFILE>>>,
+    dispatch: Callback<EditorEvent>,
+    // focusses after the next render, so it sees e.g. digits that were enabled right before
+    dispatch_after_render: Callback<EditorEvent>,
+}
+
+impl<PROFILE: DigitCodeProfile + 'static> UseDigitCodeHandle<PROFILE> {
+    /// The id of the element containing the inputs
+    pub fn id(&self) -> Option<&AttrValue> {
+        self.id.as_ref()
+    }
+    /// The current code
+    pub fn code(&self) -> &DigitCode<PROFILE> {
+        &self.code
+    }
+    /// The code including changes of events that aren't rendered yet
+    pub fn latest_code(&self) -> DigitCode<PROFILE> {
+        self.latest_code.borrow().clone()
+    }
+    /// The profile of the code
+    pub fn profile(&self) -> Arc<PROFILE> {
+        self.code.profile()
+    }
+    /// The amount of digits
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.code.len()
+    }
+    /// Checks if the inputs are disabled at the moment, during a pending submission or after `set_disabled`
+    pub fn disabled(&self) -> bool {
+        *self.disabled || *self.disabled_by_user
+    }
+    /// Checks if the inputs are readonly at the moment
+    pub fn readonly(&self) -> bool {
+        *self.readonly
+    }
+    /// The state of the last asynchronous submission
+    pub fn submit_state(&self) -> &SubmitState {
+        &self.submit_state
+    }
+    /// The css classes for the container of the digits: `pending`, `success`, `error`, `shake` and `invalid`
+    pub fn classes(&self) -> Classes {
+        classes!(self.submit_state.classes(self.submit_error_policy).to_vec())
+    }
+
+    /// How the digits are displayed if they aren't revealed
+    pub fn masking(&self) -> Masking {
+        self.masking
+    }
+    /// Checks if masked digits are shown in clear text at the moment
+    pub fn revealed(&self) -> bool {
+        *self.revealed
+    }
+    /// Checks if every digit is selected, see `DigitCodeEditor::all_selected`
+    pub fn all_selected(&self) -> bool {
+        *self.all_selected
+    }
+
+    /// The state of the digit at `index`
+    pub fn state(&self, index: usize) -> DigitState {
+        let value = self
+            .code
+            .get(index)
+            .as_ref()
+            .map(|s| s.to_string())
+            .unwrap_or_default();
+        let masking = if *self.revealed || *self.peek == Some(index) {
+            Masking::Visible
+        } else {
+            self.masking
+        };
+        let (input_type, value) = masking.display(value);
+        let grouping = self.code.profile().grouping();
+        DigitState {
+            index,
+            value,
+            input_type: input_type.to_owned(),
+            input_mode: self.code.profile().input_mode(index).to_owned(),
+            disabled: self.disabled(),
+            readonly: *self.readonly,
+            group: grouping.group_of(index),
+            separator_before: grouping
+                .separator_before(index)
+                .then_some(grouping.separator),
+            unused: self.code.is_unused(index),
+            selected: *self.all_selected,
+        }
+    }
+    /// The event handlers of the digit at `index`
+    pub fn handlers(&self, index: usize) -> DigitHandlers {
+        let readonly = *self.readonly;
+        let selected = *self.all_selected;
+        DigitHandlers {
+            oninput: handle_input(
+                index,
+                self.state(index).value,
+                readonly,
+                self.dispatch.clone(),
+            ),
+            onkeydown: handle_keydown(index, readonly, selected, self.dispatch.clone()),
+            onpaste: handle_paste(index, readonly, self.dispatch.clone()),
+            onclick: handle_click(index, selected, self.dispatch.clone()),
+            node_ref: self.node_refs.get(index).cloned().unwrap_or_default(),
+        }
+    }
+    /// State and event handlers of all digits
+    pub fn digits(&self) -> impl Iterator<Item = (DigitState, DigitHandlers)> + '_ {
+        (0..self.len()).map(|index| (self.state(index), self.handlers(index)))
+    }
+
+    /// Sends an event to the editor and applies its effects
+    pub fn dispatch(&self, event: EditorEvent) {
+        self.dispatch.emit(event);
+    }
+    /// Removes all digits
+    pub fn clear(&self) {
+        self.dispatch(EditorEvent::Clear);
+    }
+    /// Focusses the digit at `index` after the next render, so changes made before (e.g. `set_disabled(false)`) apply
+    pub fn focus(&self, index: usize) {
+        self.dispatch_after_render
+            .emit(EditorEvent::FocusIndex(index));
+    }
+    /// Focusses the first empty digit after the next render, or the last one if every digit is filled
+    pub fn focus_first_empty(&self) {
+        self.dispatch_after_render
+            .emit(EditorEvent::FocusFirstEmpty);
+    }
+    /// Focusses the last empty digit after the next render, or the last one if every digit is filled
+    pub fn focus_last_empty(&self) {
+        self.dispatch_after_render.emit(EditorEvent::FocusLastEmpty);
+    }
+    /// Selects every digit after the next render, see `EditorEvent::SelectAll`
+    pub fn select_all(&self) {
+        self.dispatch_after_render.emit(EditorEvent::SelectAll);
+    }
+    /// Replaces all digits with the text, see `EditorEvent::SetValue`
+    pub fn set_value(&self, text: impl Into<String>) {
+        self.dispatch(EditorEvent::SetValue(text.into()));
+    }
+    /// Sets or removes the digit at `index`, see `EditorEvent::SetDigit`
+    pub fn set_digit(&self, index: usize, value: Option<String>) {
+        self.dispatch(EditorEvent::SetDigit { index, value });
+    }
+    /// Disables all digits until it's called with `false`
+    pub fn set_disabled(&self, disabled: bool) {
+        self.disabled_by_user.set(disabled);
+    }
+    /// Makes all digits readonly until it's called with `false`.
+    ///
+    /// Readonly digits can be focussed and the code can be submitted with `Enter`, but not edited.
+    pub fn set_readonly(&self, readonly: bool) {
+        self.readonly.set(readonly);
+    }
+    /// Shows the code as invalid (css class `invalid`) until the next edit
+    pub fn mark_invalid(&self, error: DigitCodeError) {
+        self.submit_state.set(SubmitState::Invalid(error));
+    }
+    /// Shows the code as rejected (css class `error`) until the next edit
+    pub fn show_error(&self, message: impl Into<String>) {
+        self.submit_state.set(SubmitState::Error(message.into()));
+    }
+    /// Shows masked digits in clear text or masks them again
+    pub fn set_revealed(&self, revealed: bool) {
+        self.revealed.set(revealed);
+    }
+    /// Submits the code if it's complete
+    pub fn submit(&self) {
+        self.dispatch(EditorEvent::Enter { index: 0 });
+    }
+}
+
+/// Provides the behaviour of `CodeDigitInput` for custom markup.
+///
+/// The returned handle contains the state and event handlers of every digit.
+/// Every input needs the `node_ref` of its handlers as `ref`, otherwise it can't be focussed.
+///
+/// # Examples
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew_digit_code::{use_digit_code, DigitCodeConfig, TotpCodeProfile};
+///
+/// #[function_component(CustomCode)]
+/// fn custom_code() -> Html {
+///     let code = use_digit_code(DigitCodeConfig::<TotpCodeProfile>::default());
+///
+///     html!(
+///         <label>
+///             {"Your code"}
+///             {
+///                 code.digits().map(|(digit, handlers)| html!(
+///                     <input key={digit.index} ref={handlers.node_ref} value={digit.value} type={digit.input_type}
+///                         inputmode={digit.input_mode} disabled={digit.disabled} maxlength="1"
+///                         class={classes!(digit.selected.then_some("selected"))} onclick={handlers.onclick}
+///                         oninput={handlers.oninput} onkeydown={handlers.onkeydown} onpaste={handlers.onpaste}/>
+///                 )).collect::<Html>()
+///             }
+///         </label>
+///     )
+/// }
+/// ```
+#[hook]
+pub fn use_digit_code<PROFILE>(config: DigitCodeConfig<PROFILE>) -> UseDigitCodeHandle<PROFILE>
+where
+    PROFILE: DigitCodeProfile + 'static,
+{
+    let DigitCodeConfig {
+        id,
+        profile,
+        submit_code,
+        submit_code_async,
+        submit_error_policy,
+        masking,
+        mask_peek,
+        value,
+        onchange,
+        oninvalid,
+    } = config;
+    let masking = masking.unwrap_or_else(|| profile.masking());
+    let code = use_state(|| DigitCode::new(Arc::new(profile.clone())));
+    // every digit needs its own reference, cloned node refs would point to the same node
+    let node_refs = use_memo(profile.len(), |len| {
+        (0..*len).map(|_| NodeRef::default()).collect::<Vec<_>>()
+    });
+    // events dispatched before the next render need to see the changes of the previous ones
+    let latest_code = use_mut_ref(|| (*code).clone());
+    let disabled = use_state(|| false);
+    let disabled_by_user = use_state_eq(|| false);
+    let readonly = use_state_eq(|| false);
+    let submit_state = use_state(SubmitState::default);
+    let all_selected = use_state_eq(|| false);
+    let latest_selected = use_mut_ref(|| false);
+    // a new profile (e.g. another length) starts with an empty code
+    if *code.profile() != profile {
+        #[cfg(feature = "log")]
+        log::debug!("Profile of {id:?} changed: {profile:?}");
+        let new_code = DigitCode::new(Arc::new(profile));
+        *latest_code.borrow_mut() = new_code.clone();
+        code.set(new_code);
+        submit_state.set(SubmitState::Idle);
+        *latest_selected.borrow_mut() = false;
+        all_selected.set(false);
+    }
+    let revealed = use_state_eq(|| false);
+    let peek = use_state_eq(|| None);
+    let peek_generation = use_mut_ref(|| 0_u64);
+
+    let submit_code: Callback<String> = submit_code.unwrap_or_else(|| {
+        #[cfg(feature = "log")]
+        return Callback::from(move |code| {
+            log::info!("Default submit method of digit code: {code}");
+        });
+        #[allow(unused)]
+        Callback::default()
+    });
+    let focus_after_render = use_mut_ref(|| None);
+    let rerender = {
+        let force_update = use_force_update();
+        Callback::from(move |_| force_update.force_update())
+    };
+    {
+        let node_refs = node_refs.clone();
+        let focus_after_render = focus_after_render.clone();
+        use_effect(move || {
+            let focus = focus_after_render.borrow_mut().take();
+            if let Some((index, select)) = focus {
+                if let Some(node) = node_refs.get(index) {
+                    focus_node(node, select);
+                }
+            }
+        });
+    }
+
+    let dispatcher = Dispatcher {
+        node_refs: node_refs.clone(),
+        submit_code,
+        submit_code_async,
+        submit_error_policy,
+        onchange: onchange.unwrap_or_default(),
+        oninvalid: oninvalid.unwrap_or_default(),
+        code_state: code.clone(),
+        latest_code: latest_code.clone(),
+        disabled_input: disabled.clone(),
+        submit_state: submit_state.clone(),
+        all_selected: all_selected.clone(),
+        latest_selected,
+        focus_after_render,
+        rerender,
+        defer_focus: false,
+        peek: (masking != Masking::Visible)
+            .then_some(mask_peek)
+            .flatten()
+            .map(|duration| (duration, peek.clone(), peek_generation)),
+    };
+    let dispatch_after_render = {
+        let dispatcher = Dispatcher {
+            defer_focus: true,
+            ..dispatcher.clone()
+        };
+        Callback::from(move |event| dispatcher.emit(event))
+    };
+    let dispatch = Callback::from(move |event| dispatcher.emit(event));
+
+    // the last value that couldn't be applied, otherwise it would be dispatched again after every render
+    let unmatched_value = use_mut_ref(|| None);
+    {
+        let dispatch = dispatch.clone();
+        let latest_code = latest_code.clone();
+        use_effect(move || {
+            let Some(PartialCode { digits, .. }) = value else {
+                return;
+            };
+            let matches = |digits: &Vec<Option<String>>| {
+                let current = latest_code.borrow().partial().digits;
+                current.len() >= digits.len()
+                    && current
+                        .iter()
+                        .zip(digits.iter().chain(std::iter::repeat(&None)))
+                        .all(|(a, b)| a == b)
+            };
+            if matches(&digits) || unmatched_value.borrow().as_ref() == Some(&digits) {
+                return;
+            }
+            dispatch.emit(EditorEvent::SetDigits(digits.clone()));
+            let applied = matches(&digits);
+            *unmatched_value.borrow_mut() = (!applied).then_some(digits);
+        });
+    }
+
+    UseDigitCodeHandle {
+        id,
+        code,
+        disabled,
+        disabled_by_user,
+        readonly,
+        submit_state,
+        submit_error_policy,
+        masking,
+        revealed,
+        peek,
+        all_selected,
+        node_refs,
+        latest_code,
+        dispatch,
+        dispatch_after_render,
+    }
+}
+
+/// Applies events to the editor and performs the resulting effects
+#[derive(Clone)]
+struct Dispatcher<PROFILE: DigitCodeProfile + 'static> {
+    node_refs: Rc<Vec<NodeRef>>,
+    submit_code: Callback<String>,
+    submit_code_async: Option<Callback<String, SubmitFuture>>,
+    submit_error_policy: SubmitErrorPolicy,
+    onchange: Callback<PartialCode>,
+    oninvalid: Callback<DigitCodeError>,
+    code_state: UseStateHandle<DigitCode<PROFILE>>,
+    latest_code: Rc<RefCell<DigitCode<PROFILE>>>,
+    disabled_input: UseStateHandle<bool>,
+    submit_state: UseStateHandle<SubmitState>,
+    // digits can't be focussed while they are disabled so this gets applied after the next render
+    focus_after_render: Rc<RefCell<Option<(usize, bool)>>>,
+    // `Focus` effects wait for the next render as well, it's triggered with `rerender`
+    defer_focus: bool,
+    rerender: Callback<()>,
+    all_selected: UseStateHandle<bool>,
+    // the selection of events dispatched before the next render
+    latest_selected: Rc<RefCell<bool>>,
+    // the digit that is shown unmasked for a moment after it was typed
+    #[allow(clippy::type_complexity)]
+    peek: Option<(Duration, UseStateHandle<Option<usize>>, Rc<RefCell<u64>>)>,
+}
+
+impl<PROFILE: DigitCodeProfile + 'static> Dispatcher<PROFILE> {
+    fn emit(&self, event: EditorEvent) {
+        let typed = match event {
+            EditorEvent::Input { index, .. } => Some(index),
+            _ => None,
+        };
+        let mut editor = DigitCodeEditor::from(self.latest_code.borrow().clone())
+            .with_all_selected(*self.latest_selected.borrow());
+        let effects = editor.handle(event);
+        if editor.all_selected() != *self.latest_selected.borrow() {
+            *self.latest_selected.borrow_mut() = editor.all_selected();
+            self.all_selected.set(editor.all_selected());
+        }
+        if let Some(index) = typed.filter(|index| editor.code().get(*index).is_some()) {
+            self.peek(index);
+        }
+        #[cfg(feature = "log")]
+        log::trace!("Editor produced {effects:?} with {:?}", editor.code());
+
+        for effect in effects {
+            match effect {
+                EditorEffect::ValueChanged => {
+                    *self.latest_code.borrow_mut() = editor.code().clone();
+                    self.code_state.set(editor.code().clone());
+                    if *self.submit_state != SubmitState::Idle {
+                        self.submit_state.set(SubmitState::Idle);
+                    }
+                    self.onchange.emit(editor.code().partial());
+                }
+                EditorEffect::Focus(index) => self.focus(index, editor.all_selected()),
+                EditorEffect::Submit(code) => self.submit(code),
+                EditorEffect::Invalid(error) => {
+                    #[cfg(feature = "log")]
+                    log::debug!("Input was rejected: {error}");
+                    self.submit_state.set(SubmitState::Invalid(error.clone()));
+                    self.oninvalid.emit(error);
+                }
+            }
+        }
+    }
+
+    fn focus(&self, index: usize, select: bool) {
+        if self.defer_focus {
+            *self.focus_after_render.borrow_mut() = Some((index, select));
+            self.rerender.emit(());
+        } else if let Some(node) = self.node_refs.get(index) {
+            focus_node(node, select);
+        }
+    }
+
+    fn peek(&self, index: usize) {
+        let Some((duration, peek, generation)) = self.peek.clone() else {
+            return;
+        };
+        let current = {
+            let mut generation = generation.borrow_mut();
+            *generation += 1;
+            *generation
+        };
+        peek.set(Some(index));
+        spawn_local(async move {
+            yew::platform::time::sleep(duration).await;
+            // a newer digit could be shown in the meantime
+            if *generation.borrow() == current {
+                peek.set(None);
+            }
+        });
+    }
+
+    fn submit(&self, code: String) {
+        let Some(submit_code_async) = &self.submit_code_async else {
+            self.disabled_input.set(true);
+            self.submit_code.emit(code);
+            self.disabled_input.set(false);
+            return;
+        };
+        self.disabled_input.set(true);
+        self.submit_state.set(SubmitState::Pending);
+        let future = submit_code_async.emit(code);
+        // the digits are still disabled until the next render
+        let this = Self {
+            defer_focus: true,
+            ..self.clone()
+        };
+        spawn_local(async move {
+            let result = future.await;
+            #[cfg(feature = "log")]
+            log::debug!("Asynchronous submission finished: {result:?}");
+            this.disabled_input.set(false);
+            match result {
+                Ok(()) => this.submit_state.set(SubmitState::Success),
+                Err(message) => {
+                    match this.submit_error_policy {
+                        SubmitErrorPolicy::ClearAndRefocus => {
+                            this.emit(EditorEvent::Clear);
+                            this.emit(EditorEvent::FocusIndex(0));
+                        }
+                        SubmitErrorPolicy::KeepAndSelectAll => this.emit(EditorEvent::SelectAll),
+                        SubmitErrorPolicy::Shake => {}
+                    }
+                    this.submit_state.set(SubmitState::Error(message));
+                }
+            }
+        });
+    }
+}
+
+fn handle_input(
+    index: usize,
+    previous: String,
+    readonly: bool,
+    dispatch: Callback<EditorEvent>,
+) -> Callback<InputEvent> {
+    Callback::from(move |e: InputEvent| {
+        if readonly {
+            return;
+        }
+        let target: Option<EventTarget> = e.target();
+        let input = target.and_then(|t| t.dyn_into::<HtmlInputElement>().ok());
+        if let Some(input) = input {
+            let value: String = input.value();
+
+            // more than one char gets inserted by autofill or if maxlength is ignored
+            // the editor splits them, so the digit needs to show its old value until it gets rerendered
+            if value.chars().nth(1).is_some() {
+                input.set_value(&previous);
+            }
+            dispatch.emit(EditorEvent::Input { index, value });
+        }
+    })
+}
+
+fn handle_paste(index: usize, readonly: bool, dispatch: Callback<EditorEvent>) -> Callback<Event> {
+    Callback::from(move |e: Event| {
+        if readonly {
+            return;
+        }
+        let text = e
+            .dyn_ref::<ClipboardEvent>()
+            .and_then(|e| e.clipboard_data())
+            .and_then(|data| data.get_data("text").ok());
+        if let Some(text) = text {
+            e.prevent_default();
+            dispatch.emit(EditorEvent::Paste { index, text });
+        }
+    })
+}
+
+fn handle_keydown(
+    index: usize,
+    readonly: bool,
+    selected: bool,
+    dispatch: Callback<EditorEvent>,
+) -> Callback<KeyboardEvent> {
+    Callback::from(move |e: KeyboardEvent| {
+        let key = e.key();
+        #[cfg(feature = "log")]
+        log::trace!("Keydown: {key}");
+
+        // chars typed into a selected code are written by the editor, starting at the first digit
+        let selected_char = selected && Grapheme::split(&key).nth(1).is_none();
+        if key == "Backspace" || (selected_char && !readonly) {
+            e.prevent_default();
+        }
+        // readonly digits can only be navigated and submitted
+        if readonly && !matches!(key.as_str(), "ArrowLeft" | "ArrowRight" | "Enter") {
+            return;
+        }
+        dispatch.emit(EditorEvent::KeyDown { index, key });
+    })
+}
+
+fn handle_click(
+    index: usize,
+    selected: bool,
+    dispatch: Callback<EditorEvent>,
+) -> Callback<MouseEvent> {
+    Callback::from(move |_: MouseEvent| {
+        // clicking a digit ends the selection, so typing continues there
+        if selected {
+            dispatch.emit(EditorEvent::FocusIndex(index));
+        }
+    })
+}
@@ -1,68 +1,58 @@
+use std::rc::Rc;
+
 use yew::prelude::*;
 use yew::{html, Html};
 
 use crate::control_flags::ControlFlags;
-use crate::{ArcStrOrChar, DigitCode};
+use crate::{
+    use_digit_code, DigitCodeConfig, DigitCodeHandle, Masking, PartialCode, SubmitErrorPolicy,
+    SubmitFuture,
+};
 
 use super::single_digit_element::CodeSingleDigitElement;
 use super::DigitCodeProfile;
 
 #[derive(Properties, PartialEq)]
 pub struct Props<PROFILE: DigitCodeProfile + 'static> {
-    pub id: AttrValue,
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
     #[prop_or_default]
     pub submit_code: Option<Callback<String>>,
-    pub code: UseStateHandle<super::DigitCode<PROFILE>>,
+    #[prop_or_default]
+    pub submit_code_async: Option<Callback<String, SubmitFuture>>,
+    #[prop_or_default]
+    pub submit_error_policy: SubmitErrorPolicy,
+    pub profile: PROFILE,
     pub flags: UseStateHandle<ControlFlags>,
     #[prop_or_default]
     pub class: Classes,
-    #[cfg(feature = "yew-hooks")]
+    #[prop_or_default]
+    pub one_time_code: bool,
+    #[prop_or_default]
+    pub masking: Option<Masking>,
+    #[prop_or_default]
+    pub mask_peek: Option<std::time::Duration>,
+    #[prop_or_default]
+    pub value: Option<PartialCode>,
+    #[prop_or_default]
+    pub onchange: Option<Callback<PartialCode>>,
+    #[prop_or_default]
+    pub oninvalid: Option<Callback<crate::DigitCodeError>>,
     #[prop_or_default]
     pub oninit: Callback<AttrValue>,
-}
-
-fn enter_hit<PROFILE: DigitCodeProfile + 'static>(
-    submit_code: Callback<String>,
-    code_state: UseStateHandle<DigitCode<PROFILE>>,
-    disabled_input: UseStateHandle<bool>,
-) -> Callback<usize> {
-    Callback::from(move |_: usize| {
-        let code_state = code_state.clone();
-        #[cfg(feature = "log")]
-        log::trace!("Enter hit: {:?}", *code_state);
-        if let Some(code) = (*code_state).joined() {
-            disabled_input.set(true);
-            submit_code.emit(code);
-            disabled_input.set(false);
-        }
-    })
-}
-
-fn set_value<PROFILE: DigitCodeProfile + 'static>(
-    submit_code: Callback<String>,
-    code_state: UseStateHandle<DigitCode<PROFILE>>,
-    disabled_input: UseStateHandle<bool>,
-) -> Callback<(usize, Option<ArcStrOrChar>)> {
-    Callback::from(move |(index, value)| {
-        #[cfg(feature = "log")]
-        log::trace!("{index} called set_value with {value:?}");
-        let code_state = code_state.clone();
-        let mut v: DigitCode<PROFILE> = (*code_state).clone();
-        v.change_update_indicator();
-        let value: Option<ArcStrOrChar> = value;
-        v.set(index, value);
-        code_state.set(v.clone());
-
-        if index == v.len() - 1 {
-            if let Some(code) = v.joined() {
-                disabled_input.set(true);
-                submit_code.emit(code);
-                disabled_input.set(false);
-            }
-        }
-        #[cfg(feature = "log")]
-        log::trace!("{index} call to set_value produced {:?}", v);
-    })
+    #[prop_or_default]
+    pub handle: Option<DigitCodeHandle>,
+    #[prop_or_default]
+    pub onready: Option<Callback<DigitCodeHandle>>,
+    #[cfg(feature = "web-otp")]
+    #[prop_or_default]
+    pub web_otp: bool,
+    #[cfg(feature = "web-otp")]
+    #[prop_or_default]
+    pub web_otp_timeout: Option<std::time::Duration>,
+    #[cfg(feature = "web-otp")]
+    #[prop_or_default]
+    pub onwebotp: Callback<crate::WebOtpOutcome>,
 }
 
 #[function_component(InnerCodeDigitInput)]
@@ -70,108 +60,136 @@ pub fn inner_code_digit_element<PROFILE: DigitCodeProfile + 'static>(
     Props {
         id,
         submit_code,
-        code,
+        submit_code_async,
+        submit_error_policy,
+        profile,
         flags,
         class,
-        #[cfg(feature = "yew-hooks")]
+        one_time_code,
+        masking,
+        mask_peek,
+        value,
+        onchange,
+        oninvalid,
         oninit,
+        handle,
+        onready,
+        #[cfg(feature = "web-otp")]
+        web_otp,
+        #[cfg(feature = "web-otp")]
+        web_otp_timeout,
+        #[cfg(feature = "web-otp")]
+        onwebotp,
     }: &Props<PROFILE>,
 ) -> Html {
-    #[cfg(feature = "yew-hooks")]
-    let is_initialized = {
-        let is_initialized_state = use_state_eq(|| false);
-        use yew_hooks::prelude::*;
-        let cloned_is_initialized = is_initialized_state.clone();
-
-        let id = id.clone();
-        #[cfg(feature = "log")]
-        log::trace!("Try creating interval: id=\"{id}\"");
+    let code = use_digit_code(DigitCodeConfig {
+        id: id.clone(),
+        profile: profile.clone(),
+        submit_code: submit_code.clone(),
+        submit_code_async: submit_code_async.clone(),
+        submit_error_policy: *submit_error_policy,
+        masking: *masking,
+        mask_peek: *mask_peek,
+        value: value.clone(),
+        onchange: onchange.clone(),
+        oninvalid: oninvalid.clone(),
+    });
+    let digit_count = code.len();
+
+    // effects run after the digits are mounted, so flags set before can focus them right away
+    {
+        let code = code.clone();
+        let flags = flags.clone();
+        use_effect_with((*flags).clone(), move |current_flags| {
+            if *current_flags == ControlFlags::default() {
+                return;
+            }
+            #[cfg(feature = "log")]
+            log::debug!("Processing flags: {current_flags:?}");
+            // the order is documented at `ControlFlags`
+            for command in current_flags.clone().commands() {
+                command.apply(&code);
+            }
+            flags.set(ControlFlags::default());
+        });
+    }
 
-        let oninit = oninit.clone();
-        use_interval(
-            move || {
-                if crate::focus_offset::document().is_some() {
-                    cloned_is_initialized.set(true);
-                    #[cfg(feature = "log")]
-                    log::debug!("Init complete: id=\"{id}\"");
-                    oninit.emit(id.clone());
-                }
-            },
-            if *is_initialized_state { 0 } else { 100 },
+    // commands of the handle are queued and processed after every render, the handle
+    // rerenders the component when it gets a command
+    let handle = use_memo(handle.clone(), |handle| handle.clone().unwrap_or_default());
+    {
+        let force_update = use_force_update();
+        let cloned_code = code.clone();
+        handle.connect(
+            Callback::from(move |_| force_update.force_update()),
+            Rc::new(move || cloned_code.latest_code().partial()),
         );
-        *is_initialized_state
-    };
-    #[cfg(not(feature = "yew-hooks"))]
-    let is_initialized = true;
-
-    let id = id.to_string();
-
-    use super::focus_offset::{focus_offset, FocusOffset};
-    let profile = code.profile();
-    let whole_code_state = code.clone();
-
-    let disabled_input = use_state(|| false);
-
-    let id = id.to_string();
-    let digit_count = profile.len();
-
-    let submit_code: Callback<String> = submit_code.clone().unwrap_or_else(|| {
-        #[cfg(feature = "log")]
-        return Callback::from(move |code| {
-            log::info!("Default submit method of digit code: {code}");
+    }
+    {
+        let code = code.clone();
+        let handle = handle.clone();
+        use_effect(move || {
+            for command in handle.take_commands() {
+                #[cfg(feature = "log")]
+                log::debug!("Processing command: {command:?}");
+                command.apply(&code);
+            }
         });
-        #[allow(unused)]
-        Callback::default()
-    });
-
-    let offset_closure_next = focus_offset(id.to_string(), digit_count, FocusOffset::Next);
-    let offset_closure_prev = focus_offset(id.to_string(), digit_count, FocusOffset::Previous);
-
-    let focus_next = Callback::from(move |i: usize| offset_closure_next(i));
-    let focus_prev = Callback::from(move |i: usize| offset_closure_prev(i));
-
-    #[cfg(feature = "log")]
-    log::trace!("Try working on flags: {is_initialized}");
-    if is_initialized {
-        let current_flags = (*flags).clone();
-        let mut builder = current_flags.change();
-
-        if let Some(focus_num) = builder.focus {
-            builder = builder.unset_focus();
-            if focus_num == 0 {
-                focus_prev.emit(1);
-            } else {
-                focus_next.emit(focus_num - 1);
+    }
+    {
+        let handle = handle.clone();
+        use_effect_with(handle, |handle| {
+            let handle = handle.clone();
+            move || handle.disconnect()
+        });
+    }
+    {
+        let id = id.clone().unwrap_or_default();
+        let oninit = oninit.clone();
+        use_effect_with((), move |_| {
+            #[cfg(feature = "log")]
+            log::debug!("Init complete: id=\"{id}\"");
+            oninit.emit(id);
+        });
+    }
+    {
+        let handle = handle.clone();
+        let onready = onready.clone();
+        use_effect_with((), move |_| {
+            if let Some(onready) = onready {
+                onready.emit((*handle).clone());
             }
-        }
-        if builder.clear.is_some() {
-            builder = builder.unset_clear();
-            whole_code_state.set(whole_code_state.as_empty());
-        }
-        let new_flags = builder.apply();
-        if new_flags != *current_flags {
-            current_flags.set(new_flags);
-        }
+        });
     }
 
-    let enter_hit = enter_hit(
-        submit_code.clone(),
-        whole_code_state.clone(),
-        disabled_input.clone(),
-    );
-    let set_value = set_value(
-        submit_code,
-        whole_code_state.clone(),
-        disabled_input.clone(),
-    );
+    #[cfg(feature = "web-otp")]
+    {
+        let cloned_profile = code.profile();
+        let cloned_code = code.clone();
+        let oncode = Callback::from(move |text: String| {
+            let valid = cloned_profile.is_str_code_valid(&text);
+            if valid {
+                cloned_code.dispatch(crate::EditorEvent::Paste { index: 0, text });
+            }
+            valid
+        });
+        crate::web_otp::use_web_otp(*web_otp, *web_otp_timeout, oncode, onwebotp.clone());
+    }
 
     html!(
-        <div class={classes!("nice-digit-code-container-view", class.clone())} id={id} code_length={digit_count.to_string()}>
+        <div class={classes!("nice-digit-code-container-view", code.classes(), class.clone())} id={id} code_length={digit_count.to_string()}>
             <div class={classes!("digit-code-container")}>
             {
-                (0..digit_count)
+                code.digits()
                 .map(
-                    |index| html!(<CodeSingleDigitElement<PROFILE> profile={profile.clone()} key={index} whole_code={whole_code_state.clone()} index={index} focus_next={focus_next.clone()} focus_prev={focus_prev.clone()} enter_hit={enter_hit.clone()} disabled={*disabled_input} set_value={set_value.clone()}/>)
+                    |(state, handlers)| html!(
+                        <key={state.index}>
+                            if let Some(separator) = state.separator_before.clone() {
+                                <span class="digit-code-separator" aria-hidden="true">{separator}</span>
+                            }
+                            <CodeSingleDigitElement state={state.clone()} handlers={handlers} one_time_code={*one_time_code}/>
+                        </>
+                    )
                 ).collect::<Vec<_>>()
              }
             </div>
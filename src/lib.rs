@@ -7,39 +7,73 @@
 //!
 //! The example repository: <https://github.com/heureka-code/yew-digit-code-example>
 //!
+//! The editing logic of the component is available without `yew` as `DigitCodeEditor`.
+//!
 //! ---
 //!
 //! This crate uses the `unicode-segmentation` crate as a dependency (behind a feature flag).
-//! The signatures of this crate's methods depend highly on this flag so be aware of that.
-//! `unicode-segmentation` is needed if your alphabet contains characters that can't be represented in a single rust char.
-//! If your codes only use ASCII you can disable this feature.
+//! It's needed if your alphabet contains characters that can't be represented in a single rust char,
+//! the text of every digit is a `Grapheme` in both cases. If your codes only use ASCII you can disable this feature.
 //!
 //! # Features
 //! - `log` _(default)_: adds few log messages using `log` crate
-//! - `default-id` _(default)_: generates a random id (`rand` crate) where a unique html id is needed. When disabled the user has to choose one.
-//! - `unicode-segmentation`: needed for unicode alphabets (changes method signatures)
+//! - `default-id` _(default)_: generates a random html id (`rand` crate) for the container if none is given. Ids are optional, the digits are focussed with node references
+//! - `unicode-segmentation` _(default)_: needed for unicode alphabets, no interface changes. Texts get split into grapheme clusters instead of chars
 //! - `itertools`: no interface changes. Some implementations differ (joining strings and chars). Itertools is more efficient for sure
 //! - `serde`: not needed but adds some implementations
-//! - `yew-hooks` _(default)_: activates `yew-hooks` to provide a callback that's called after initialization of the component
+//! - `yew-hooks`: has no effect and is only kept for compatibility. The `oninit` callback is always available
+//! - `otp`: HOTP and TOTP generation and verification in the `otp` module (`hmac`, `sha1` and `sha2` crates)
+//! - `otpauth`: parses `otpauth://` URIs of authenticator QR codes in the `otpauth` module, enables `otp`
+//! - `web-otp`: requests SMS codes with the WebOTP API (`js-sys` crate) if the component asks for it
 
+mod boxed_profile;
+pub mod checksum;
 mod code_element;
 mod control_flags;
 mod digit_code_status;
+mod editor;
+mod error;
 pub mod extra;
 mod focus_offset;
+mod grapheme;
+mod grouping;
+mod handle;
+mod hook;
 mod inner_code_element;
+mod masking;
+pub mod normalize;
+#[cfg(feature = "otp")]
+pub mod otp;
+#[cfg(feature = "otpauth")]
+pub mod otpauth;
 mod predefined;
 mod profile;
 mod single_digit_element;
+mod submit;
+#[cfg(feature = "web-otp")]
+mod web_otp;
 
-use digit_code_status::ArcStrOrChar;
-use digit_code_status::DigitCode;
-use profile::RefStrOrChar;
-
+pub use boxed_profile::BoxedProfile;
 pub use code_element::CodeDigitInput;
 pub use control_flags::ControlFlags;
-pub use predefined::TotpCodeProfile;
+pub use digit_code_status::{DigitCode, PartialCode};
+pub use editor::{DigitCodeEditor, EditorEffect, EditorEvent};
+pub use error::DigitCodeError;
+pub use focus_offset::FocusOffset;
+pub use grapheme::Grapheme;
+pub use grouping::Grouping;
+pub use handle::DigitCodeHandle;
+pub use hook::{use_digit_code, DigitCodeConfig, DigitHandlers, DigitState, UseDigitCodeHandle};
+pub use masking::Masking;
+pub use predefined::{
+    AlphanumericCodeProfile, Base32CodeProfile, CharsetProfile, CrockfordCodeProfile,
+    DynamicCodeProfile, HexCodeProfile, MaskCodeProfile, PinCodeProfile, SteamGuardCodeProfile,
+    TotpCodeProfile,
+};
 pub use profile::DigitCodeProfile;
+pub use submit::{SubmitErrorPolicy, SubmitFuture, SubmitState};
+#[cfg(feature = "web-otp")]
+pub use web_otp::WebOtpOutcome;
 
 /// This is a type alias for a `CodeDigitInput` with a `TotpCodeProfile` of the given length.
 /// `LENGTH` defaults to 6
@@ -75,3 +109,34 @@ pub use profile::DigitCodeProfile;
 /// }
 /// ```
 pub type TotpInput<const LENGTH: usize = 6> = CodeDigitInput<TotpCodeProfile<LENGTH>>;
+
+/// This is a type alias for a `CodeDigitInput` with a `BoxedProfile`, the profile can be switched at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew_digit_code::{BoxedInput, BoxedProfile, CrockfordCodeProfile, TotpCodeProfile};
+///
+/// #[function_component(LoginCodeComponent)]
+/// fn login_code_component() -> Html {
+///     let backup_code = use_state_eq(|| false);
+///     let profile = if *backup_code {
+///         BoxedProfile::new(CrockfordCodeProfile::<10>::new())
+///     } else {
+///         BoxedProfile::new(TotpCodeProfile::<6>::new())
+///     };
+///     let onclick = {
+///         let backup_code = backup_code.clone();
+///         Callback::from(move |_| backup_code.set(!*backup_code))
+///     };
+///
+///     html!(
+///         <>
+///             <BoxedInput profile={profile}/>
+///             <button {onclick}>{"Use a backup code"}</button>
+///         </>
+///     )
+/// }
+/// ```
+pub type BoxedInput = CodeDigitInput<BoxedProfile>;
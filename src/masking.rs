@@ -0,0 +1,27 @@
+/// How the digits of a code are displayed
+///
+/// The profile chooses a default with `DigitCodeProfile::masking`, the `masking` attribute of
+/// `CodeDigitInput` overrides it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Masking {
+    /// The digits are shown in clear text
+    #[default]
+    Visible,
+    /// The inputs get `type="password"`
+    Password,
+    /// Every filled digit shows this glyph instead of its char
+    Glyph(char),
+}
+
+impl Masking {
+    /// The html input type and the displayed text of a digit with the given value
+    pub(crate) fn display(self, value: String) -> (&'static str, String) {
+        match self {
+            Masking::Visible => ("text", value),
+            Masking::Password => ("password", value),
+            Masking::Glyph(_) if value.is_empty() => ("text", value),
+            Masking::Glyph(glyph) => ("text", glyph.to_string()),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Helpers to implement `DigitCodeProfile::normalize_char`.
+//!
+//! The functions map a single `char`, use `map_char` to apply them to the text of a digit.
+//!
+//! ```
+//! use yew_digit_code::normalize::{
+//!     ascii_digit, crockford, half_width, map_char, matching_case, uppercase,
+//! };
+//!
+//! assert_eq!(ascii_digit('٣'), '3');
+//! assert_eq!(ascii_digit('７'), '7');
+//! assert_eq!(half_width('Ａ'), 'A');
+//! assert_eq!(uppercase('x'), 'X');
+//! assert_eq!(crockford('o'), '0');
+//! assert_eq!(crockford('l'), '1');
+//! assert_eq!(matching_case('b', "ABC"), 'B');
+//! assert_eq!(map_char("ｂ", |c| uppercase(half_width(c))), "B");
+//! ```
+
+use crate::Grapheme;
+
+/// Applies `f` to every char of the text of a digit
+pub fn map_char(chr: &str, f: impl Fn(char) -> char) -> Grapheme {
+    Grapheme::from(chr.chars().map(f).collect::<String>())
+}
+
+/// Maps the full-width forms of ASCII chars (e.g. `Ａ` or `１`) to ASCII
+pub fn half_width(chr: char) -> char {
+    match chr {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(chr as u32 - 0xFF01 + 0x21).unwrap_or(chr),
+        '\u{3000}' => ' ',
+        _ => chr,
+    }
+}
+
+/// Maps decimal digits of other scripts (full-width, Arabic-Indic, Devanagari, ...) to ASCII digits
+pub fn ascii_digit(chr: char) -> char {
+    const ZEROS: [u32; 6] = [0xFF10, 0x0660, 0x06F0, 0x0966, 0x09E6, 0x0E50];
+    ZEROS
+        .iter()
+        .find(|&&zero| (zero..zero + 10).contains(&(chr as u32)))
+        .and_then(|&zero| char::from_digit(chr as u32 - zero, 10))
+        .unwrap_or(chr)
+}
+
+/// Converts the char to uppercase if the uppercase form is a single char
+pub fn uppercase(chr: char) -> char {
+    let mut upper = chr.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) => upper,
+        _ => chr,
+    }
+}
+
+/// Converts the char to uppercase or lowercase if only that form is part of the alphabet
+pub fn matching_case(chr: char, alphabet: &str) -> char {
+    [
+        chr,
+        uppercase(chr),
+        chr.to_lowercase().next().unwrap_or(chr),
+    ]
+    .into_iter()
+    .find(|&c| alphabet.contains(c))
+    .unwrap_or(chr)
+}
+
+/// Crockford Base32 decoding rules: uppercase, `O` becomes `0`, `I` and `L` become `1`
+pub fn crockford(chr: char) -> char {
+    match uppercase(half_width(chr)) {
+        'O' => '0',
+        'I' | 'L' => '1',
+        upper => ascii_digit(upper),
+    }
+}
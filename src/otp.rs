@@ -0,0 +1,251 @@
+//! Generation and verification of one-time passwords: HOTP ([RFC 4226](https://www.rfc-editor.org/rfc/rfc4226))
+//! and TOTP ([RFC 6238](https://www.rfc-editor.org/rfc/rfc6238)).
+//!
+//! This module is only available with the `otp` feature. It's meant for demos, tests and development servers,
+//! the generated codes are accepted by a `TotpCodeProfile` with the same amount of digits.
+//!
+//! ```
+//! use yew_digit_code::otp::{Hotp, OtpAlgorithm, Totp};
+//! use yew_digit_code::{DigitCodeProfile, TotpCodeProfile};
+//!
+//! // RFC 4226, appendix D
+//! let hotp = Hotp::new(b"12345678901234567890".to_vec());
+//! let codes: Vec<_> = (0..10).map(|counter| hotp.generate(counter)).collect();
+//! assert_eq!(
+//!     codes,
+//!     vec![
+//!         "755224", "287082", "359152", "969429", "338314", "254676", "287922", "162583",
+//!         "399871", "520489"
+//!     ]
+//! );
+//! assert!(hotp.verify("969429", 3));
+//! assert_eq!(hotp.verify_window("162583", 5, 3), Some(7));
+//! assert_eq!(hotp.verify_window("162583", 2, 3), None);
+//!
+//! // RFC 6238, appendix B
+//! let sha1 = Totp::new(b"12345678901234567890".to_vec()).digits(8);
+//! let sha256 = Totp::new(b"12345678901234567890123456789012".to_vec())
+//!     .algorithm(OtpAlgorithm::Sha256)
+//!     .digits(8);
+//! let sha512 = Totp::new(
+//!     b"1234567890123456789012345678901234567890123456789012345678901234".to_vec(),
+//! )
+//! .algorithm(OtpAlgorithm::Sha512)
+//! .digits(8);
+//! let vectors = [
+//!     (59, "94287082", "46119246", "90693936"),
+//!     (1111111109, "07081804", "68084774", "25091201"),
+//!     (1111111111, "14050471", "67062674", "99943326"),
+//!     (1234567890, "89005924", "91819424", "93441116"),
+//!     (2000000000, "69279037", "90698825", "38618901"),
+//!     (20000000000, "65353130", "77737706", "47863826"),
+//! ];
+//! for (time, code_sha1, code_sha256, code_sha512) in vectors {
+//!     assert_eq!(sha1.generate_at(time), code_sha1);
+//!     assert_eq!(sha256.generate_at(time), code_sha256);
+//!     assert_eq!(sha512.generate_at(time), code_sha512);
+//! }
+//!
+//! // codes of the previous or next period are accepted with a skew of 1
+//! let totp = Totp::new(b"12345678901234567890".to_vec()).skew(1);
+//! let code = totp.generate_at(1111111109);
+//! assert!(totp.verify_at(&code, 1111111109 + 30));
+//! assert!(!totp.verify_at(&code, 1111111109 + 60));
+//! assert!(TotpCodeProfile::<6>::new().is_str_code_valid(&code));
+//! assert_eq!(totp.profile().len(), 6);
+//! assert_eq!(totp.period, 30);
+//! ```
+
+use hmac::digest::KeyInit;
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+
+use crate::DynamicCodeProfile;
+
+/// The hash function of the HMAC
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OtpAlgorithm {
+    /// HMAC-SHA1, used by most authenticator apps
+    #[default]
+    Sha1,
+    /// HMAC-SHA256
+    Sha256,
+    /// HMAC-SHA512
+    Sha512,
+}
+
+impl OtpAlgorithm {
+    fn sign(&self, key: &[u8], message: &[u8]) -> Vec<u8> {
+        match self {
+            OtpAlgorithm::Sha1 => sign::<Hmac<Sha1>>(key, message),
+            OtpAlgorithm::Sha256 => sign::<Hmac<Sha256>>(key, message),
+            OtpAlgorithm::Sha512 => sign::<Hmac<Sha512>>(key, message),
+        }
+    }
+}
+
+fn sign<M: Mac + KeyInit>(key: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut mac = <M as KeyInit>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Compares two codes in constant time
+fn codes_equal(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Counter based one-time passwords (RFC 4226)
+///
+/// By default codes have 6 digits and use `OtpAlgorithm::Sha1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hotp {
+    /// The secret key
+    pub secret: Vec<u8>,
+    /// The hash function
+    pub algorithm: OtpAlgorithm,
+    /// The amount of digits of the codes, 6 to 8 are common. Codes can't have more than 10 significant digits.
+    pub digits: usize,
+}
+
+impl Hotp {
+    /// Creates a generator for the secret
+    pub fn new(secret: impl Into<Vec<u8>>) -> Self {
+        Self {
+            secret: secret.into(),
+            algorithm: OtpAlgorithm::default(),
+            digits: 6,
+        }
+    }
+    /// Uses another hash function
+    pub fn algorithm(mut self, algorithm: OtpAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+    /// Sets the amount of digits of the codes
+    pub fn digits(mut self, digits: usize) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// The code for the counter
+    pub fn generate(&self, counter: u64) -> String {
+        let hash = self.algorithm.sign(&self.secret, &counter.to_be_bytes());
+        // dynamic truncation
+        let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            hash[offset] & 0x7f,
+            hash[offset + 1],
+            hash[offset + 2],
+            hash[offset + 3],
+        ]);
+        let code = u64::from(binary) % 10u64.saturating_pow(self.digits as u32);
+        format!("{code:0width$}", width = self.digits)
+    }
+    /// Checks if the code belongs to the counter
+    pub fn verify(&self, code: &str, counter: u64) -> bool {
+        codes_equal(&self.generate(counter), code)
+    }
+    /// Checks the counters from `counter` to `counter + look_ahead` and returns the one the code belongs to.
+    ///
+    /// This resynchronizes a server with a client whose counter is ahead.
+    pub fn verify_window(&self, code: &str, counter: u64, look_ahead: u64) -> Option<u64> {
+        (counter..=counter.saturating_add(look_ahead)).find(|counter| self.verify(code, *counter))
+    }
+
+    /// A profile for the codes of this generator
+    pub fn profile(&self) -> DynamicCodeProfile {
+        DynamicCodeProfile::new(self.digits, "0123456789")
+    }
+}
+
+/// Time based one-time passwords (RFC 6238)
+///
+/// By default codes have 6 digits, use `OtpAlgorithm::Sha1` and change every 30 seconds.
+/// Times are seconds since the unix epoch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Totp {
+    /// The generator of the code of every period, the counter is the time step
+    pub hotp: Hotp,
+    /// The seconds a code is valid
+    pub period: u64,
+    /// The amount of periods before and after the current one whose codes are accepted as well
+    pub skew: u64,
+}
+
+impl Totp {
+    /// Creates a generator for the secret
+    pub fn new(secret: impl Into<Vec<u8>>) -> Self {
+        Self {
+            hotp: Hotp::new(secret),
+            period: 30,
+            skew: 0,
+        }
+    }
+    /// Uses another hash function
+    pub fn algorithm(mut self, algorithm: OtpAlgorithm) -> Self {
+        self.hotp.algorithm = algorithm;
+        self
+    }
+    /// Sets the amount of digits of the codes
+    pub fn digits(mut self, digits: usize) -> Self {
+        self.hotp.digits = digits;
+        self
+    }
+    /// Sets the seconds a code is valid
+    pub fn period(mut self, period: u64) -> Self {
+        self.period = period;
+        self
+    }
+    /// Sets the amount of periods before and after the current one whose codes are accepted as well
+    pub fn skew(mut self, skew: u64) -> Self {
+        self.skew = skew;
+        self
+    }
+
+    /// The counter of the period the time belongs to, a period of 0 is handled like 1 second
+    pub fn time_step(&self, time: u64) -> u64 {
+        time / self.period.max(1)
+    }
+    /// The code at the time
+    pub fn generate_at(&self, time: u64) -> String {
+        self.hotp.generate(self.time_step(time))
+    }
+    /// The code at the current time
+    pub fn generate(&self) -> String {
+        self.generate_at(unix_time())
+    }
+    /// Checks if the code is valid at the time, including the periods of the skew
+    pub fn verify_at(&self, code: &str, time: u64) -> bool {
+        let step = self.time_step(time);
+        (step.saturating_sub(self.skew)..=step.saturating_add(self.skew))
+            .any(|counter| self.hotp.verify(code, counter))
+    }
+    /// Checks if the code is valid at the current time, including the periods of the skew
+    pub fn verify(&self, code: &str) -> bool {
+        self.verify_at(code, unix_time())
+    }
+
+    /// A profile for the codes of this generator
+    pub fn profile(&self) -> DynamicCodeProfile {
+        self.hotp.profile()
+    }
+}
+
+/// The seconds since the unix epoch, in the browser it's taken from `Date.now()`
+fn unix_time() -> u64 {
+    #[cfg(target_arch = "wasm32")]
+    let time = (js_sys::Date::now() / 1000.0) as u64;
+    #[cfg(not(target_arch = "wasm32"))]
+    let time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    time
+}
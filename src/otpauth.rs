@@ -0,0 +1,293 @@
+//! Parser for `otpauth://` URIs, the format of the QR codes that configure authenticator apps.
+//!
+//! This module is only available with the `otpauth` feature. It follows the
+//! [key uri format](https://github.com/google/google-authenticator/wiki/Key-Uri-Format):
+//! `otpauth://TYPE/LABEL?PARAMETERS` where the label is `issuer:account` or `account`.
+//!
+//! ```
+//! use yew_digit_code::otp::OtpAlgorithm;
+//! use yew_digit_code::otpauth::{OtpAuthError, OtpAuthUri, OtpKind};
+//! use yew_digit_code::DigitCodeProfile;
+//!
+//! let uri: OtpAuthUri = "otpauth://totp/Example:alice@google.com?secret=JBSWY3DPEHPK3PXP&issuer=Example"
+//!     .parse()
+//!     .unwrap();
+//! assert_eq!(uri.kind, OtpKind::Totp);
+//! assert_eq!(uri.issuer.as_deref(), Some("Example"));
+//! assert_eq!(uri.account, "alice@google.com");
+//! assert_eq!(uri.secret, b"Hello!\xde\xad\xbe\xef");
+//! assert_eq!((uri.algorithm, uri.digits, uri.period), (OtpAlgorithm::Sha1, 6, 30));
+//! assert_eq!(uri.profile().len(), 6);
+//! assert_eq!(uri.totp().unwrap().generate_at(59), "996554");
+//!
+//! let uri = OtpAuthUri::parse(
+//!     "otpauth://totp/ACME%20Co:john.doe@email.com?secret=HXDMVJECJJWSRB3HWIZR4IFUGFTMXBOZ\
+//!      &issuer=ACME%20Co&algorithm=SHA256&digits=8&period=60",
+//! )
+//! .unwrap();
+//! assert_eq!(uri.issuer.as_deref(), Some("ACME Co"));
+//! assert_eq!(uri.account, "john.doe@email.com");
+//! assert_eq!((uri.algorithm, uri.digits, uri.period), (OtpAlgorithm::Sha256, 8, 60));
+//! assert_eq!(uri.profile().len(), 8);
+//! assert_eq!(uri.totp().unwrap().generate_at(56_666_666 * 60), "71688188");
+//!
+//! // without issuer, lowercase secret with padding
+//! let uri = OtpAuthUri::parse("otpauth://totp/alice%40example.com?secret=jbswy3dpehpk3pxp%3D%3D%3D%3D").unwrap();
+//! assert_eq!((uri.issuer, uri.account.as_str()), (None, "alice@example.com"));
+//! assert_eq!(uri.secret, b"Hello!\xde\xad\xbe\xef");
+//!
+//! let uri = OtpAuthUri::parse("otpauth://hotp/Example:alice?secret=JBSWY3DPEHPK3PXP&counter=42").unwrap();
+//! assert_eq!((uri.kind, uri.counter), (OtpKind::Hotp, Some(42)));
+//! assert_eq!(uri.hotp().generate(42), "090604");
+//! assert_eq!(uri.totp(), None);
+//!
+//! assert_eq!(OtpAuthUri::parse("https://example.com"), Err(OtpAuthError::InvalidScheme));
+//! assert_eq!(
+//!     OtpAuthUri::parse("otpauth://sms/alice?secret=JBSWY3DPEHPK3PXP"),
+//!     Err(OtpAuthError::UnknownKind("sms".into()))
+//! );
+//! assert_eq!(OtpAuthUri::parse("otpauth://totp/alice?digits=6"), Err(OtpAuthError::MissingSecret));
+//! assert_eq!(OtpAuthUri::parse("otpauth://totp/alice?secret=JBSW1"), Err(OtpAuthError::InvalidSecret));
+//! assert_eq!(OtpAuthUri::parse("otpauth://hotp/alice?secret=JBSWY3DP"), Err(OtpAuthError::MissingCounter));
+//! assert_eq!(
+//!     OtpAuthUri::parse("otpauth://totp/alice?secret=JBSWY3DP&algorithm=MD5").unwrap_err().to_string(),
+//!     "the parameter algorithm has the invalid value \"MD5\""
+//! );
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::otp::{Hotp, OtpAlgorithm, Totp};
+use crate::DynamicCodeProfile;
+
+/// The type of the one-time password
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OtpKind {
+    /// Time based (`otpauth://totp/...`)
+    Totp,
+    /// Counter based (`otpauth://hotp/...`)
+    Hotp,
+}
+
+/// An error of a URI that couldn't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OtpAuthError {
+    /// The URI doesn't start with `otpauth://`
+    InvalidScheme,
+    /// The type isn't `totp` or `hotp`
+    UnknownKind(String),
+    /// The label doesn't contain an account name
+    MissingAccount,
+    /// A `%` isn't followed by two hex digits or the decoded text isn't UTF-8
+    InvalidPercentEncoding,
+    /// The URI has no `secret` parameter
+    MissingSecret,
+    /// The secret isn't valid base32
+    InvalidSecret,
+    /// A HOTP URI has no `counter` parameter
+    MissingCounter,
+    /// A parameter has a value that isn't supported
+    InvalidParameter { name: String, value: String },
+}
+
+impl Display for OtpAuthError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OtpAuthError::InvalidScheme => write!(f, "the URI doesn't start with otpauth://"),
+            OtpAuthError::UnknownKind(kind) => write!(f, "the type {kind:?} isn't supported"),
+            OtpAuthError::MissingAccount => write!(f, "the label doesn't contain an account"),
+            OtpAuthError::InvalidPercentEncoding => write!(f, "the URI isn't percent encoded"),
+            OtpAuthError::MissingSecret => write!(f, "the secret is missing"),
+            OtpAuthError::InvalidSecret => write!(f, "the secret isn't valid base32"),
+            OtpAuthError::MissingCounter => write!(f, "the counter of the hotp URI is missing"),
+            OtpAuthError::InvalidParameter { name, value } => {
+                write!(f, "the parameter {name} has the invalid value {value:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OtpAuthError {}
+
+/// The content of an `otpauth://` URI, see the module documentation
+///
+/// `profile` returns a profile with the right amount of digits for a `CodeDigitInput<DynamicCodeProfile>`,
+/// `totp` and `hotp` a generator for the codes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OtpAuthUri {
+    /// Time or counter based
+    pub kind: OtpKind,
+    /// The provider, taken from the `issuer` parameter or the prefix of the label
+    pub issuer: Option<String>,
+    /// The account name of the label
+    pub account: String,
+    /// The decoded secret key
+    pub secret: Vec<u8>,
+    /// The hash function, `SHA1` by default
+    pub algorithm: OtpAlgorithm,
+    /// The amount of digits, 6 by default
+    pub digits: usize,
+    /// The seconds a TOTP code is valid, 30 by default
+    pub period: u64,
+    /// The initial counter of a HOTP code
+    pub counter: Option<u64>,
+}
+
+impl OtpAuthUri {
+    /// Parses the URI
+    pub fn parse(uri: &str) -> Result<Self, OtpAuthError> {
+        let rest = uri
+            .get(.."otpauth://".len())
+            .filter(|scheme| scheme.eq_ignore_ascii_case("otpauth://"))
+            .map(|scheme| &uri[scheme.len()..])
+            .ok_or(OtpAuthError::InvalidScheme)?;
+        let (kind, rest) = rest.split_once('/').unwrap_or((rest, ""));
+        let kind = match kind.to_ascii_lowercase().as_str() {
+            "totp" => OtpKind::Totp,
+            "hotp" => OtpKind::Hotp,
+            _ => return Err(OtpAuthError::UnknownKind(kind.to_owned())),
+        };
+        let (label, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+        let label = percent_decode(label, false)?;
+        let (mut issuer, account) = match label.split_once(':') {
+            Some((issuer, account)) => (Some(issuer.trim().to_owned()), account.trim()),
+            None => (None, label.trim()),
+        };
+        if account.is_empty() {
+            return Err(OtpAuthError::MissingAccount);
+        }
+
+        let mut parsed = Self {
+            kind,
+            issuer: None,
+            account: account.to_owned(),
+            secret: vec![],
+            algorithm: OtpAlgorithm::default(),
+            digits: 6,
+            period: 30,
+            counter: None,
+        };
+        let mut secret = None;
+        for parameter in query.split('&').filter(|p| !p.is_empty()) {
+            let (name, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+            let value = percent_decode(value, true)?;
+            let invalid = || OtpAuthError::InvalidParameter {
+                name: name.to_owned(),
+                value: value.clone(),
+            };
+            match name.to_ascii_lowercase().as_str() {
+                "secret" => {
+                    secret = Some(decode_base32(&value).ok_or(OtpAuthError::InvalidSecret)?)
+                }
+                "issuer" => issuer = Some(value.trim().to_owned()),
+                "algorithm" => {
+                    parsed.algorithm = match value.to_ascii_uppercase().as_str() {
+                        "SHA1" => OtpAlgorithm::Sha1,
+                        "SHA256" => OtpAlgorithm::Sha256,
+                        "SHA512" => OtpAlgorithm::Sha512,
+                        _ => return Err(invalid()),
+                    }
+                }
+                "digits" => {
+                    parsed.digits = value
+                        .parse()
+                        .ok()
+                        .filter(|digits| (1..=10).contains(digits))
+                        .ok_or_else(invalid)?
+                }
+                "period" => {
+                    parsed.period = value
+                        .parse()
+                        .ok()
+                        .filter(|period| *period > 0)
+                        .ok_or_else(invalid)?
+                }
+                "counter" => parsed.counter = Some(value.parse().map_err(|_| invalid())?),
+                // e.g. `image` of some apps
+                _ => {}
+            }
+        }
+
+        parsed.secret = secret.ok_or(OtpAuthError::MissingSecret)?;
+        if parsed.secret.is_empty() {
+            return Err(OtpAuthError::InvalidSecret);
+        }
+        if kind == OtpKind::Hotp && parsed.counter.is_none() {
+            return Err(OtpAuthError::MissingCounter);
+        }
+        parsed.issuer = issuer.filter(|issuer| !issuer.is_empty());
+        Ok(parsed)
+    }
+
+    /// A numeric profile with the amount of digits of the URI
+    pub fn profile(&self) -> DynamicCodeProfile {
+        self.hotp().profile()
+    }
+    /// The counter based generator, for TOTP URIs the counter is the time step
+    pub fn hotp(&self) -> Hotp {
+        Hotp::new(self.secret.clone())
+            .algorithm(self.algorithm)
+            .digits(self.digits)
+    }
+    /// The time based generator if it's a TOTP URI
+    pub fn totp(&self) -> Option<Totp> {
+        (self.kind == OtpKind::Totp).then(|| Totp {
+            hotp: self.hotp(),
+            period: self.period,
+            skew: 0,
+        })
+    }
+}
+
+impl FromStr for OtpAuthUri {
+    type Err = OtpAuthError;
+
+    fn from_str(uri: &str) -> Result<Self, Self::Err> {
+        Self::parse(uri)
+    }
+}
+
+/// Decodes `%XX` escapes and `+` (only in the query) as space
+fn percent_decode(text: &str, query: bool) -> Result<String, OtpAuthError> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut iter = text.bytes();
+    while let Some(byte) = iter.next() {
+        bytes.push(match byte {
+            b'%' => {
+                let hex = [iter.next(), iter.next()];
+                let [Some(high), Some(low)] = hex.map(|b| b.and_then(|b| (b as char).to_digit(16)))
+                else {
+                    return Err(OtpAuthError::InvalidPercentEncoding);
+                };
+                (high * 16 + low) as u8
+            }
+            b'+' if query => b' ',
+            byte => byte,
+        });
+    }
+    String::from_utf8(bytes).map_err(|_| OtpAuthError::InvalidPercentEncoding)
+}
+
+/// Decodes base32 (RFC 4648) ignoring case, padding and spaces
+fn decode_base32(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = vec![];
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for chr in text.chars().filter(|c| !c.is_whitespace() && *c != '=') {
+        let value = match chr.to_ascii_uppercase() {
+            c @ 'A'..='Z' => c as u32 - 'A' as u32,
+            c @ '2'..='7' => c as u32 - '2' as u32 + 26,
+            _ => return None,
+        };
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(bytes)
+}
@@ -1,3 +1,21 @@
+mod alphanumeric;
+mod base32;
+mod charset;
+mod crockford;
+mod dynamic;
+mod hex;
+mod mask;
+mod pin;
+mod steam_guard;
 mod totp;
 
+pub use alphanumeric::AlphanumericCodeProfile;
+pub use base32::Base32CodeProfile;
+pub use charset::CharsetProfile;
+pub use crockford::CrockfordCodeProfile;
+pub use dynamic::DynamicCodeProfile;
+pub use hex::HexCodeProfile;
+pub use mask::{CharClass, MaskCodeProfile, MaskError};
+pub use pin::PinCodeProfile;
+pub use steam_guard::SteamGuardCodeProfile;
 pub use totp::TotpCodeProfile;
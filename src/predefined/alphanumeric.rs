@@ -0,0 +1,38 @@
+use crate::normalize::{half_width, map_char, uppercase};
+use crate::{DigitCodeProfile, Grapheme};
+
+/// Predefined code profile for uppercase letters and digits (`A-Z`, `0-9`).
+///
+/// The length is given as a generic argument of type `usize` and defaults to 8.
+/// Lowercase and full-width chars are converted to uppercase ASCII.
+///
+/// ```
+/// use yew_digit_code::{AlphanumericCodeProfile, DigitCodeProfile};
+///
+/// let profile = AlphanumericCodeProfile::<6>::new();
+/// assert!(profile.is_str_code_valid("AB12CD"));
+/// assert!(profile.is_str_code_valid("ab12cd"));
+/// assert!(!profile.is_str_code_valid("AB12C?"));
+/// assert!(!profile.is_str_code_valid("AB12C"));
+/// assert_eq!(profile.input_mode(0), "text");
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct AlphanumericCodeProfile<const LENGTH: usize = 8>;
+
+impl<const LENGTH: usize> AlphanumericCodeProfile<LENGTH> {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<const LENGTH: usize> DigitCodeProfile for AlphanumericCodeProfile<LENGTH> {
+    fn len(&self) -> usize {
+        LENGTH
+    }
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool {
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789".contains(char)
+    }
+    fn normalize_char(&self, chr: &str) -> Grapheme {
+        map_char(chr, |c| uppercase(half_width(c)))
+    }
+}
@@ -0,0 +1,37 @@
+use crate::normalize::{half_width, map_char, uppercase};
+use crate::{DigitCodeProfile, Grapheme};
+
+/// Predefined code profile for the RFC 4648 Base32 alphabet (`A-Z`, `2-7`), e.g. for backup codes.
+///
+/// The length is given as a generic argument of type `usize` and defaults to 8.
+/// Lowercase letters are converted to uppercase.
+///
+/// ```
+/// use yew_digit_code::{Base32CodeProfile, DigitCodeProfile};
+///
+/// let profile = Base32CodeProfile::<8>::new();
+/// assert!(profile.is_str_code_valid("MZXW6YTB"));
+/// assert!(profile.is_str_code_valid("mzxw6ytb"));
+/// // 0, 1, 8 and 9 aren't part of the alphabet
+/// assert!(!profile.is_str_code_valid("MZXW6YT1"));
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Base32CodeProfile<const LENGTH: usize = 8>;
+
+impl<const LENGTH: usize> Base32CodeProfile<LENGTH> {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<const LENGTH: usize> DigitCodeProfile for Base32CodeProfile<LENGTH> {
+    fn len(&self) -> usize {
+        LENGTH
+    }
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool {
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567".contains(char)
+    }
+    fn normalize_char(&self, chr: &str) -> Grapheme {
+        map_char(chr, |c| uppercase(half_width(c)))
+    }
+}
@@ -0,0 +1,78 @@
+use std::borrow::Cow;
+
+use crate::normalize::{half_width, map_char, matching_case};
+use crate::{DigitCodeProfile, Grapheme};
+
+/// Code profile with a custom alphabet.
+///
+/// The length is given as a generic argument of type `usize`, the alphabet is a string containing
+/// every allowed char. The default alphabet consists of the digits `0-9`.
+/// The input mode is `numeric` if the alphabet only contains digits, otherwise `text`.
+///
+/// ```
+/// use yew_digit_code::{CharsetProfile, DigitCodeProfile};
+///
+/// let profile = CharsetProfile::<4>::new("ABC123");
+/// assert!(profile.is_str_code_valid("A1B2"));
+/// assert!(!profile.is_str_code_valid("a1b2"));
+/// assert!(!profile.is_str_code_valid("A1B4"));
+/// assert_eq!(profile.input_mode(0), "text");
+///
+/// let profile = profile.case_insensitive();
+/// assert!(profile.is_str_code_valid("a1b2"));
+///
+/// assert_eq!(CharsetProfile::<4>::default().input_mode(0), "numeric");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct CharsetProfile<const LENGTH: usize> {
+    alphabet: Cow<'static, str>,
+    case_insensitive: bool,
+}
+
+impl<const LENGTH: usize> Default for CharsetProfile<LENGTH> {
+    fn default() -> Self {
+        Self::new("0123456789")
+    }
+}
+
+impl<const LENGTH: usize> CharsetProfile<LENGTH> {
+    /// Creates a profile that accepts every char of `alphabet`
+    pub fn new(alphabet: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            alphabet: alphabet.into(),
+            case_insensitive: false,
+        }
+    }
+    /// Typed chars that aren't part of the alphabet are replaced by their uppercase or lowercase
+    /// form if that one is part of it.
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+    /// The chars accepted by this profile
+    pub fn alphabet(&self) -> &str {
+        &self.alphabet
+    }
+}
+
+impl<const LENGTH: usize> DigitCodeProfile for CharsetProfile<LENGTH> {
+    fn len(&self) -> usize {
+        LENGTH
+    }
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool {
+        self.alphabet.contains(char)
+    }
+    fn normalize_char(&self, chr: &str) -> Grapheme {
+        if !self.case_insensitive {
+            return Grapheme::from(chr);
+        }
+        map_char(chr, |c| matching_case(half_width(c), &self.alphabet))
+    }
+    fn input_mode(&self, _index: usize) -> &str {
+        if self.alphabet.chars().all(|c| c.is_ascii_digit()) {
+            "numeric"
+        } else {
+            "text"
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::normalize::{crockford, map_char};
+use crate::{DigitCodeProfile, Grapheme};
+
+/// Predefined code profile for the Crockford Base32 alphabet (`0-9` and `A-Z` without `I`, `L`, `O`, `U`).
+///
+/// The length is given as a generic argument of type `usize` and defaults to 8.
+/// Lookalikes are tolerated: lowercase letters are converted to uppercase, `O` becomes `0`
+/// and `I` and `L` become `1`.
+///
+/// ```
+/// use yew_digit_code::{CrockfordCodeProfile, DigitCodeEditor, DigitCodeProfile, EditorEvent};
+///
+/// let profile = CrockfordCodeProfile::<6>::new();
+/// assert!(profile.is_str_code_valid("3K9T0W"));
+/// assert!(profile.is_str_code_valid("3k9tow"));
+/// assert!(!profile.is_str_code_valid("3K9T0U"));
+///
+/// let mut editor = DigitCodeEditor::<CrockfordCodeProfile<6>>::default();
+/// editor.handle(EditorEvent::Paste { index: 0, text: "io-lo98".into() });
+/// assert_eq!(editor.code().joined(), Some("101098".to_string()));
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct CrockfordCodeProfile<const LENGTH: usize = 8>;
+
+impl<const LENGTH: usize> CrockfordCodeProfile<LENGTH> {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<const LENGTH: usize> DigitCodeProfile for CrockfordCodeProfile<LENGTH> {
+    fn len(&self) -> usize {
+        LENGTH
+    }
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool {
+        "0123456789ABCDEFGHJKMNPQRSTVWXYZ".contains(char)
+    }
+    fn normalize_char(&self, chr: &str) -> Grapheme {
+        map_char(chr, crockford)
+    }
+}
@@ -0,0 +1,198 @@
+use crate::normalize::{half_width, map_char, matching_case};
+use crate::{DigitCodeProfile, Grapheme, Grouping, Masking};
+
+/// Code profile whose length, alphabet and appearance are chosen at runtime,
+/// e.g. from a challenge descriptor sent by the server.
+///
+/// With the `serde` feature it can be deserialized, missing fields are taken from the default:
+/// a numeric code with 6 digits. Descriptors with a `length` or group size of 0 or a `min_length` above
+/// the `length` are rejected.
+///
+/// ```
+/// use yew_digit_code::{DigitCodeProfile, DynamicCodeProfile, Grouping};
+///
+/// let profile = DynamicCodeProfile {
+///     grouping: Grouping::every(4, "-"),
+///     ..DynamicCodeProfile::new(8, "0123456789ABCDEF")
+/// }
+/// .case_insensitive();
+/// assert_eq!(profile.len(), 8);
+/// assert!(profile.is_str_code_valid("00ff-a1b2"));
+/// assert_eq!(profile.input_mode(3), "text");
+///
+/// // codes with 4 to 8 digits
+/// let profile = DynamicCodeProfile::new(8, "0123456789").min_length(4);
+/// assert!(profile.is_str_code_valid("1234"));
+/// assert!(profile.is_str_code_valid("12345678"));
+/// assert!(!profile.is_str_code_valid("123"));
+///
+/// # #[cfg(feature = "serde")]
+/// # {
+/// let profile: DynamicCodeProfile = serde_json::from_str(
+///     r#"{ "length": 6, "alphabet": "0123456789", "input_modes": ["numeric"], "masking": "Password" }"#
+/// ).unwrap();
+/// assert!(profile.is_str_code_valid("123456"));
+/// assert_eq!(profile.input_mode(5), "numeric");
+///
+/// assert!(serde_json::from_str::<DynamicCodeProfile>(
+///     r#"{ "length": 4, "grouping": { "sizes": [0], "separator": "-" } }"#
+/// ).is_err());
+/// assert!(serde_json::from_str::<DynamicCodeProfile>(r#"{ "length": 4, "min_length": 6 }"#).is_err());
+/// assert!(serde_json::from_str::<DynamicCodeProfile>(r#"{ "length": 0 }"#).is_err());
+///
+/// use serde::Deserialize;
+/// let mut deserializer = serde_json::Deserializer::from_str(r#"{ "length": 4, "min_length": 6 }"#);
+/// assert!(DynamicCodeProfile::deserialize(&mut deserializer).is_err());
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawDynamicCodeProfile"))]
+pub struct DynamicCodeProfile {
+    /// The amount of digits, the maximum if `min_length` is set
+    pub length: usize,
+    /// The minimum amount of digits of codes with a variable length, `None` if all `length` digits are needed
+    pub min_length: Option<usize>,
+    /// Every char that is allowed in a digit
+    pub alphabet: String,
+    /// The html input mode of the digit with the same index.
+    ///
+    /// Digits without an entry use the last one, without entries it's `numeric` for
+    /// alphabets of digits and `text` otherwise.
+    pub input_modes: Vec<String>,
+    /// How the digits are split into visual groups
+    pub grouping: Grouping,
+    /// How the digits are displayed
+    pub masking: Masking,
+    /// Typed chars are converted to uppercase or lowercase if only that form is part of the alphabet
+    pub case_insensitive: bool,
+}
+
+impl Default for DynamicCodeProfile {
+    fn default() -> Self {
+        Self::new(6, "0123456789")
+    }
+}
+
+impl DynamicCodeProfile {
+    /// Creates a profile with the given length and alphabet
+    pub fn new(length: usize, alphabet: impl Into<String>) -> Self {
+        Self {
+            length,
+            min_length: None,
+            alphabet: alphabet.into(),
+            input_modes: vec![],
+            grouping: Grouping::none(),
+            masking: Masking::Visible,
+            case_insensitive: false,
+        }
+    }
+    /// Enables `case_insensitive`
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+    /// Accepts codes with `min_length` up to `length` digits
+    pub fn min_length(mut self, min_length: usize) -> Self {
+        self.min_length = Some(min_length);
+        self
+    }
+}
+
+/// A deserialized `DynamicCodeProfile` that isn't validated yet
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(default)]
+struct RawDynamicCodeProfile {
+    length: usize,
+    min_length: Option<usize>,
+    alphabet: String,
+    input_modes: Vec<String>,
+    grouping: Grouping,
+    masking: Masking,
+    case_insensitive: bool,
+}
+
+#[cfg(feature = "serde")]
+impl Default for RawDynamicCodeProfile {
+    fn default() -> Self {
+        let DynamicCodeProfile {
+            length,
+            min_length,
+            alphabet,
+            input_modes,
+            grouping,
+            masking,
+            case_insensitive,
+        } = DynamicCodeProfile::default();
+        Self {
+            length,
+            min_length,
+            alphabet,
+            input_modes,
+            grouping,
+            masking,
+            case_insensitive,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawDynamicCodeProfile> for DynamicCodeProfile {
+    type Error = String;
+
+    fn try_from(raw: RawDynamicCodeProfile) -> Result<Self, Self::Error> {
+        if raw.length == 0 {
+            return Err("length must not be 0".to_string());
+        }
+        if raw.grouping.sizes.contains(&0) {
+            return Err("group sizes must not be 0".to_string());
+        }
+        if let Some(min_length) = raw.min_length.filter(|min_length| *min_length > raw.length) {
+            return Err(format!(
+                "min_length {min_length} is larger than length {}",
+                raw.length
+            ));
+        }
+        Ok(Self {
+            length: raw.length,
+            min_length: raw.min_length,
+            alphabet: raw.alphabet,
+            input_modes: raw.input_modes,
+            grouping: raw.grouping,
+            masking: raw.masking,
+            case_insensitive: raw.case_insensitive,
+        })
+    }
+}
+
+impl DigitCodeProfile for DynamicCodeProfile {
+    fn len(&self) -> usize {
+        self.length
+    }
+    fn min_len(&self) -> usize {
+        self.min_length.unwrap_or(self.length).min(self.length)
+    }
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool {
+        self.alphabet.contains(char)
+    }
+    fn normalize_char(&self, chr: &str) -> Grapheme {
+        if !self.case_insensitive {
+            return Grapheme::from(chr);
+        }
+        map_char(chr, |c| matching_case(half_width(c), &self.alphabet))
+    }
+    fn input_mode(&self, index: usize) -> &str {
+        match self.input_modes.get(index).or(self.input_modes.last()) {
+            Some(mode) => mode,
+            None if self.alphabet.chars().all(|c| c.is_ascii_digit()) => "numeric",
+            None => "text",
+        }
+    }
+    fn masking(&self) -> Masking {
+        self.masking
+    }
+    fn grouping(&self) -> Grouping {
+        self.grouping.clone()
+    }
+}
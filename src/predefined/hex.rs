@@ -0,0 +1,37 @@
+use crate::normalize::{half_width, map_char};
+use crate::{DigitCodeProfile, Grapheme};
+
+/// Predefined code profile for hexadecimal codes (`0-9`, `a-f`).
+///
+/// The length is given as a generic argument of type `usize` and defaults to 8.
+/// Uppercase letters are converted to lowercase.
+///
+/// ```
+/// use yew_digit_code::{DigitCodeProfile, HexCodeProfile};
+///
+/// let profile = HexCodeProfile::<4>::new();
+/// assert!(profile.is_str_code_valid("c0de"));
+/// assert!(profile.is_str_code_valid("C0DE"));
+/// assert!(!profile.is_str_code_valid("c0dg"));
+/// assert_eq!(profile.input_mode(0), "text");
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct HexCodeProfile<const LENGTH: usize = 8>;
+
+impl<const LENGTH: usize> HexCodeProfile<LENGTH> {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<const LENGTH: usize> DigitCodeProfile for HexCodeProfile<LENGTH> {
+    fn len(&self) -> usize {
+        LENGTH
+    }
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool {
+        "0123456789abcdef".contains(char)
+    }
+    fn normalize_char(&self, chr: &str) -> Grapheme {
+        map_char(chr, |c| half_width(c).to_ascii_lowercase())
+    }
+}
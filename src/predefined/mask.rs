@@ -0,0 +1,296 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::normalize::{ascii_digit, half_width, map_char, matching_case};
+use crate::{DigitCodeProfile, Grapheme, Grouping};
+
+const DIGITS: &str = "0123456789";
+const LETTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// The chars that are allowed at one position of a `MaskCodeProfile`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CharClass {
+    /// `9`: a digit `0-9`
+    Digit,
+    /// `A`: a letter `A-Z`, lowercase letters are converted
+    Letter,
+    /// `*`: a letter or a digit
+    Alphanumeric,
+    /// `[...]`: one of the listed chars
+    Set(String),
+}
+
+impl CharClass {
+    /// Checks if the char is part of this class
+    pub fn matches(&self, chr: &str) -> bool {
+        match self {
+            CharClass::Digit => DIGITS.contains(chr),
+            CharClass::Letter => LETTERS.contains(chr),
+            CharClass::Alphanumeric => DIGITS.contains(chr) || LETTERS.contains(chr),
+            CharClass::Set(set) => set.contains(chr),
+        }
+    }
+    /// The html input mode for digits of this class
+    pub fn input_mode(&self) -> &str {
+        match self {
+            CharClass::Digit => "numeric",
+            CharClass::Set(set) if set.chars().all(|c| c.is_ascii_digit()) => "numeric",
+            _ => "text",
+        }
+    }
+}
+
+/// An error of a mask that couldn't be parsed, positions count chars of the mask
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaskError {
+    /// The mask doesn't contain any digit
+    Empty,
+    /// A `[` without matching `]`
+    UnclosedSet { position: usize },
+    /// A set without chars: `[]`
+    EmptySet { position: usize },
+    /// A `\` at the end of the mask
+    DanglingEscape,
+    /// The mask starts or ends with a separator
+    SeparatorAtEdge { position: usize },
+    /// All separators need to be the same text
+    InconsistentSeparators { expected: String, found: String },
+}
+
+impl Display for MaskError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MaskError::Empty => write!(f, "the mask doesn't contain any digit"),
+            MaskError::UnclosedSet { position } => {
+                write!(f, "the set starting at {position} isn't closed with ']'")
+            }
+            MaskError::EmptySet { position } => write!(f, "the set at {position} is empty"),
+            MaskError::DanglingEscape => write!(f, "the mask ends with an escape '\\'"),
+            MaskError::SeparatorAtEdge { position } => {
+                write!(f, "the separator at {position} isn't between two digits")
+            }
+            MaskError::InconsistentSeparators { expected, found } => {
+                write!(f, "found separator {found:?} but expected {expected:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MaskError {}
+
+/// Code profile where every position has its own alphabet, compiled from a mask like `999-AAA-***`.
+///
+/// - `9` is a digit, `A` a letter, `*` a letter or a digit
+/// - `[...]` is one of the listed chars, e.g. `[ABC]`
+/// - `\` escapes the next char, which is then a set with only this char
+/// - every other char is a separator, it's rendered between the groups and dropped when a code is pasted.
+///   All separators of a mask need to be the same text.
+///
+/// Letters are uppercase, typed lowercase letters get converted.
+///
+/// With the `serde` feature it gets (de)serialized as its mask.
+///
+/// ```
+/// use std::sync::Arc;
+/// use yew_digit_code::extra::MaskError;
+/// use yew_digit_code::{DigitCodeEditor, DigitCodeProfile, EditorEvent, MaskCodeProfile};
+///
+/// let profile: MaskCodeProfile = "AA-999-[XYZ]".parse().unwrap();
+/// assert_eq!(profile.len(), 6);
+/// assert!(profile.is_str_code_valid("AB-123-X"));
+/// assert!(profile.is_str_code_valid("ab123y"));
+/// assert!(!profile.is_str_code_valid("A1-123-X"));
+/// assert_eq!(profile.input_mode(0), "text");
+/// assert_eq!(profile.input_mode(2), "numeric");
+/// assert_eq!(profile.grouping().format("AB123X".chars()), "AB-123-X");
+///
+/// let mut editor = DigitCodeEditor::new(Arc::new(profile));
+/// editor.handle(EditorEvent::Input { index: 0, value: "1".into() });
+/// assert_eq!(editor.code().get(0), &None);
+/// editor.handle(EditorEvent::Input { index: 0, value: "q".into() });
+/// assert!(editor.code().get(0).is_some());
+///
+/// assert_eq!(MaskCodeProfile::parse("99[12"), Err(MaskError::UnclosedSet { position: 2 }));
+/// assert_eq!(MaskCodeProfile::parse("-999"), Err(MaskError::SeparatorAtEdge { position: 0 }));
+/// assert_eq!(
+///     MaskCodeProfile::parse("99-99 99").unwrap_err().to_string(),
+///     "found separator \" \" but expected \"-\""
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+pub struct MaskCodeProfile {
+    mask: String,
+    classes: Vec<CharClass>,
+    alphabet: String,
+    grouping: Grouping,
+}
+
+impl Default for MaskCodeProfile {
+    fn default() -> Self {
+        Self::parse("999999").expect("the default mask is valid")
+    }
+}
+
+impl MaskCodeProfile {
+    /// Compiles a mask, see the description of the type
+    pub fn parse(mask: &str) -> Result<Self, MaskError> {
+        let mut classes = vec![];
+        let mut group_sizes = vec![];
+        let mut group_start = 0;
+        let mut separator: Option<String> = None;
+        let mut current_separator = String::new();
+        let mut separator_start = 0;
+
+        let mut chars = mask.chars().enumerate();
+        while let Some((position, chr)) = chars.next() {
+            let class = match chr {
+                '9' => CharClass::Digit,
+                'A' => CharClass::Letter,
+                '*' => CharClass::Alphanumeric,
+                '\\' => match chars.next() {
+                    Some((_, escaped)) => CharClass::Set(escaped.to_string()),
+                    None => return Err(MaskError::DanglingEscape),
+                },
+                '[' => {
+                    let mut set = String::new();
+                    loop {
+                        match chars.next() {
+                            Some((_, ']')) => break,
+                            Some((_, chr)) => set.push(chr),
+                            None => return Err(MaskError::UnclosedSet { position }),
+                        }
+                    }
+                    if set.is_empty() {
+                        return Err(MaskError::EmptySet { position });
+                    }
+                    CharClass::Set(set)
+                }
+                chr => {
+                    if current_separator.is_empty() {
+                        separator_start = position;
+                    }
+                    current_separator.push(chr);
+                    continue;
+                }
+            };
+            if !current_separator.is_empty() {
+                if classes.is_empty() {
+                    return Err(MaskError::SeparatorAtEdge {
+                        position: separator_start,
+                    });
+                }
+                let found = std::mem::take(&mut current_separator);
+                match &separator {
+                    Some(expected) if *expected != found => {
+                        return Err(MaskError::InconsistentSeparators {
+                            expected: expected.clone(),
+                            found,
+                        })
+                    }
+                    _ => separator = Some(found),
+                }
+                group_sizes.push(classes.len() - group_start);
+                group_start = classes.len();
+            }
+            classes.push(class);
+        }
+        if classes.is_empty() {
+            return Err(MaskError::Empty);
+        }
+        if !current_separator.is_empty() {
+            return Err(MaskError::SeparatorAtEdge {
+                position: separator_start,
+            });
+        }
+
+        let grouping = match separator {
+            Some(separator) => {
+                // the last group gets a size of its own, otherwise the previous one would be repeated
+                group_sizes.push(classes.len() - group_start);
+                Grouping::new(group_sizes, separator)
+            }
+            None => Grouping::none(),
+        };
+        let mut alphabet = String::new();
+        for class in &classes {
+            let chars = match class {
+                CharClass::Digit => DIGITS,
+                CharClass::Letter => LETTERS,
+                CharClass::Alphanumeric => "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789",
+                CharClass::Set(set) => set,
+            };
+            for chr in chars.chars() {
+                if !alphabet.contains(chr) {
+                    alphabet.push(chr);
+                }
+            }
+        }
+        Ok(Self {
+            mask: mask.to_owned(),
+            classes,
+            alphabet,
+            grouping,
+        })
+    }
+    /// The mask this profile was compiled from
+    pub fn mask(&self) -> &str {
+        &self.mask
+    }
+    /// The char class of every position
+    pub fn classes(&self) -> &[CharClass] {
+        &self.classes
+    }
+}
+
+impl FromStr for MaskCodeProfile {
+    type Err = MaskError;
+
+    fn from_str(mask: &str) -> Result<Self, Self::Err> {
+        Self::parse(mask)
+    }
+}
+
+impl TryFrom<String> for MaskCodeProfile {
+    type Error = MaskError;
+
+    fn try_from(mask: String) -> Result<Self, Self::Error> {
+        Self::parse(&mask)
+    }
+}
+
+impl From<MaskCodeProfile> for String {
+    fn from(profile: MaskCodeProfile) -> Self {
+        profile.mask
+    }
+}
+
+impl DigitCodeProfile for MaskCodeProfile {
+    fn len(&self) -> usize {
+        self.classes.len()
+    }
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool {
+        self.alphabet.contains(char)
+    }
+    fn char_matches_alphabet_at(&self, index: usize, char: &str) -> bool {
+        self.classes.get(index).is_some_and(|c| c.matches(char))
+    }
+    fn normalize_char(&self, chr: &str) -> Grapheme {
+        map_char(chr, |c| {
+            matching_case(ascii_digit(half_width(c)), &self.alphabet)
+        })
+    }
+    fn input_mode(&self, index: usize) -> &str {
+        self.classes
+            .get(index)
+            .map(CharClass::input_mode)
+            .unwrap_or("text")
+    }
+    fn grouping(&self) -> Grouping {
+        self.grouping.clone()
+    }
+}
@@ -0,0 +1,43 @@
+use crate::normalize::{ascii_digit, map_char};
+use crate::{DigitCodeProfile, Grapheme, Masking};
+
+/// Predefined code profile for a numeric PIN.
+///
+/// The length is given as a generic argument of type `usize` and defaults to 4.
+/// The digits are masked like a password.
+///
+/// ```
+/// use yew_digit_code::{DigitCodeProfile, Masking, PinCodeProfile};
+///
+/// let profile = PinCodeProfile::<4>::new();
+/// assert!(profile.is_str_code_valid("0815"));
+/// assert!(!profile.is_str_code_valid("08150"));
+/// assert_eq!(profile.input_mode(0), "numeric");
+/// assert_eq!(profile.masking(), Masking::Password);
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct PinCodeProfile<const LENGTH: usize = 4>;
+
+impl<const LENGTH: usize> PinCodeProfile<LENGTH> {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<const LENGTH: usize> DigitCodeProfile for PinCodeProfile<LENGTH> {
+    fn len(&self) -> usize {
+        LENGTH
+    }
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool {
+        "0123456789".contains(char)
+    }
+    fn normalize_char(&self, chr: &str) -> Grapheme {
+        map_char(chr, ascii_digit)
+    }
+    fn input_mode(&self, _index: usize) -> &str {
+        "numeric"
+    }
+    fn masking(&self) -> Masking {
+        Masking::Password
+    }
+}
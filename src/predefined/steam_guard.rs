@@ -0,0 +1,38 @@
+use crate::normalize::{half_width, map_char, uppercase};
+use crate::{DigitCodeProfile, Grapheme};
+
+/// Predefined code profile for Steam Guard codes (`23456789BCDFGHJKMNPQRTVWXY`).
+///
+/// The length is given as a generic argument of type `usize` and defaults to 5.
+/// Lowercase letters are converted to uppercase.
+///
+/// ```
+/// use yew_digit_code::{DigitCodeProfile, SteamGuardCodeProfile};
+///
+/// let profile = SteamGuardCodeProfile::<5>::new();
+/// assert!(profile.is_str_code_valid("7QK2C"));
+/// assert!(profile.is_str_code_valid("7qk2c"));
+/// // vowels, 0 and 1 aren't part of the alphabet
+/// assert!(!profile.is_str_code_valid("7QK2A"));
+/// assert!(!profile.is_str_code_valid("7QK21"));
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct SteamGuardCodeProfile<const LENGTH: usize = 5>;
+
+impl<const LENGTH: usize> SteamGuardCodeProfile<LENGTH> {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<const LENGTH: usize> DigitCodeProfile for SteamGuardCodeProfile<LENGTH> {
+    fn len(&self) -> usize {
+        LENGTH
+    }
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool {
+        "23456789BCDFGHJKMNPQRTVWXY".contains(char)
+    }
+    fn normalize_char(&self, chr: &str) -> Grapheme {
+        map_char(chr, |c| uppercase(half_width(c)))
+    }
+}
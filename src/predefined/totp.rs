@@ -1,8 +1,19 @@
-use crate::{DigitCodeProfile, RefStrOrChar};
+use crate::normalize::{ascii_digit, map_char};
+use crate::{DigitCodeProfile, Grapheme};
 
 /// Predefined code profile for a TOTP code.
 ///
 /// The length is given as a generic argument of type `usize` and defaults to 6.
+/// Digits of other scripts like full-width or Arabic-Indic digits are converted to ASCII.
+///
+/// ```
+/// use yew_digit_code::{DigitCodeEditor, EditorEvent, TotpCodeProfile};
+///
+/// let mut editor = DigitCodeEditor::<TotpCodeProfile<4>>::default();
+/// editor.handle(EditorEvent::Input { index: 0, value: "٤".into() });
+/// editor.handle(EditorEvent::Paste { index: 1, text: "２ 4 9".into() });
+/// assert_eq!(editor.code().joined(), Some("4249".to_string()));
+/// ```
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct TotpCodeProfile<const LENGTH: usize = 6>;
 
@@ -16,9 +27,12 @@ impl<const LENGTH: usize> DigitCodeProfile for TotpCodeProfile<LENGTH> {
     fn len(&self) -> usize {
         LENGTH
     }
-    fn char_matches_alphabet_impl(&self, char: RefStrOrChar) -> bool {
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool {
         "0123456789".contains(char)
     }
+    fn normalize_char(&self, chr: &str) -> Grapheme {
+        map_char(chr, ascii_digit)
+    }
     fn input_mode(&self, _index: usize) -> &str {
         "numeric"
     }
@@ -1,58 +1,60 @@
 use std::fmt::Debug;
 
+use crate::{DigitCodeError, Grapheme, Grouping, Masking};
+
 #[cfg(feature = "itertools")]
 use itertools::Itertools;
 
-#[cfg(feature = "unicode-segmentation")]
-use unicode_segmentation::UnicodeSegmentation;
-
-#[cfg(feature = "unicode-segmentation")]
+/// The text of a single digit, it's the same type with and without `unicode-segmentation`
+#[deprecated(note = "use `&str`")]
 pub type RefStrOrChar<'a> = &'a str;
-#[cfg(not(feature = "unicode-segmentation"))]
-pub type RefStrOrChar<'a> = char;
-
-#[cfg(not(feature = "unicode-segmentation"))]
-pub(self) mod char_and_ref {
-    pub trait CharOrCharRef {
-        fn as_char(&self) -> char;
-    }
-    impl CharOrCharRef for char {
-        fn as_char(&self) -> char {
-            self.clone()
-        }
-    }
-    impl CharOrCharRef for &char {
-        fn as_char(&self) -> char {
-            **self
-        }
-    }
-}
-#[cfg(not(feature = "unicode-segmentation"))]
-use char_and_ref::CharOrCharRef;
 
 /// This trait is implemented for structs that represent a code with a fixed amount of digits and alphabet.
 ///
-/// IF YOUR ALPHABET CONTAINS UNICODE CODEPOINTS WHICH ARE LONGER THAN ONE SCALAR YOU HAVE TO ENABLE THE
-/// `unicode-segmentation` feature
+/// Codes of variable length are supported with `min_len`, `len` is the maximum then.
+///
+/// Every char is passed as `&str` containing a single `Grapheme`. If your alphabet contains chars that
+/// consist of more than one unicode scalar value you have to enable the `unicode-segmentation` feature,
+/// so texts get split into grapheme clusters.
 #[allow(clippy::len_without_is_empty)]
 pub trait DigitCodeProfile: PartialEq + Clone + Default + Debug {
-    /// This describes the amount of digits
+    /// This describes the amount of digits, the maximum if the code has a variable length
     fn len(&self) -> usize;
+    /// The minimum amount of digits of a valid code.
+    ///
+    /// Codes between `min_len` and `len` digits can be submitted with `Enter`, they're submitted
+    /// automatically once all `len` digits are filled. By default codes have exactly `len` digits.
+    fn min_len(&self) -> usize {
+        self.len()
+    }
     /// This needs to check if a given char matches the alphabet. Length is checked in other functions.
     /// Char will have length 1
-    fn char_matches_alphabet_impl(&self, char: RefStrOrChar<'_>) -> bool;
+    fn char_matches_alphabet_impl(&self, char: &str) -> bool;
 
     /// Checks if a provided text represents a valid character for a single digit
+    fn is_valid_char(&self, chr: &str) -> bool {
+        Grapheme::is_single(chr) && self.char_matches_alphabet_impl(chr)
+    }
+    /// This checks if a given char matches the alphabet of the digit at `index`.
+    ///
+    /// Override it if positions have different alphabets, by default it calls `char_matches_alphabet_impl`.
+    /// Index will be in range 0 <= index < len()
+    #[allow(unused_variables)]
+    fn char_matches_alphabet_at(&self, index: usize, char: &str) -> bool {
+        self.char_matches_alphabet_impl(char)
+    }
+    /// Checks if a provided text represents a valid character for the digit at `index`
     ///
-    /// if unicode-segmentation feature is disabled length will be checked with chars which could
-    /// result in wrong result unless you only use unicode chars with length of one char.
-    #[cfg(feature = "unicode-segmentation")]
-    fn is_valid_char(&self, chr: RefStrOrChar<'_>) -> bool {
-        chr.graphemes(true).count() == 1 && self.char_matches_alphabet_impl(chr)
+    /// Like `is_valid_char` but with `char_matches_alphabet_at`.
+    fn is_valid_char_at(&self, index: usize, chr: &str) -> bool {
+        Grapheme::is_single(chr) && self.char_matches_alphabet_at(index, chr)
     }
-    #[cfg(not(feature = "unicode-segmentation"))]
-    fn is_valid_char<'a, C: CharOrCharRef>(&self, chr: C) -> bool {
-        self.char_matches_alphabet_impl(chr.as_char())
+    /// Converts a typed or pasted char into its canonical form before it gets validated.
+    ///
+    /// This can be used for case folding or to map lookalikes like `O` to `0`, see the
+    /// `normalize` module for helpers. By default the char is kept as it is.
+    fn normalize_char(&self, chr: &str) -> Grapheme {
+        Grapheme::from(chr)
     }
     /// This methods should return the html input mode: text, numeric, ... that the digit on position `index` should have.
     /// Index will be in range 0 <= index < len()
@@ -61,37 +63,136 @@ pub trait DigitCodeProfile: PartialEq + Clone + Default + Debug {
         "text"
     }
 
-    /// This function takes an iterator over text and checks
+    /// This method returns how the digits should be displayed, e.g. masked for PINs.
+    fn masking(&self) -> Masking {
+        Masking::Visible
+    }
+
+    /// This method returns how the digits are split into visual groups, e.g. `XXXX-XXXX-XXXX`.
+    ///
+    /// The chars of the separator are dropped when a code is pasted.
+    fn grouping(&self) -> Grouping {
+        Grouping::none()
+    }
+
+    /// Checks if a provided text is a separator that gets dropped when a whole code is pasted.
     ///
-    /// 1. if every item is a valid char according to `is_valid_char`
-    /// 2. if the iterator has the correct length (number of digits)
-    fn is_char_code_valid<'a, I: Iterator<Item = RefStrOrChar<'a>>>(&'a self, chars: I) -> bool {
+    /// By default whitespace, the characters `-`, `_`, `.`, `/`, `:` and the chars of the
+    /// grouping separator are separators unless they are part of the alphabet.
+    fn is_separator(&self, chr: &str) -> bool {
+        let grouping = self.grouping().separator;
+        let separator = chr
+            .chars()
+            .all(|c| c.is_whitespace() || "-_./:".contains(c) || grouping.contains(c));
+        separator && !self.char_matches_alphabet_impl(chr)
+    }
+
+    /// Splits a text (e.g. pasted by the user) into chars and drops all separators.
+    ///
+    /// The text is split with `Grapheme::split`. The remaining chars aren't validated.
+    fn split_code<'a>(&self, code: &'a str) -> Vec<&'a str> {
+        Grapheme::split(code)
+            .filter(|chr| !self.is_separator(chr))
+            .collect()
+    }
+
+    /// Splits a text with `split_code` and normalizes every char with `normalize_char`.
+    fn normalize_code(&self, code: &str) -> Vec<Grapheme> {
+        self.split_code(code)
+            .into_iter()
+            .map(|chr| self.normalize_char(chr))
+            .collect()
+    }
+
+    /// Checks a complete code as a whole, e.g. its check digit. See the `checksum` module.
+    ///
+    /// It's only called with codes of the right length where every char is valid.
+    /// By default every code is valid.
+    #[allow(unused_variables)]
+    fn validate_code(&self, code: &str) -> bool {
+        true
+    }
+
+    /// This function takes an iterator over text, checks
+    ///
+    /// 1. if every item is a valid char for its position according to `is_valid_char_at`
+    /// 2. if the iterator has a correct length (between `min_len` and `len` digits)
+    /// 3. if the whole code passes `validate_code`
+    ///
+    /// and returns the joined code or the first problem it found.
+    fn check_char_code<'a, I: Iterator<Item = &'a str>>(
+        &'a self,
+        chars: I,
+    ) -> Result<String, DigitCodeError> {
+        let mut code = String::new();
         let mut len = 0;
-        for char in chars {
+        for (index, char) in chars.enumerate() {
             len += 1;
-            if !self.is_valid_char(char) {
-                return false;
+            if index >= self.len() {
+                return Err(DigitCodeError::OutOfRange { index });
+            }
+            if !self.is_valid_char_at(index, char) {
+                return Err(DigitCodeError::InvalidChar {
+                    index,
+                    value: char.to_string(),
+                });
             }
+            code.extend([char]);
+        }
+        if len < self.min_len() {
+            return Err(DigitCodeError::Incomplete {
+                missing: (len..self.min_len()).collect(),
+            });
+        }
+        if !self.validate_code(&code) {
+            return Err(DigitCodeError::ChecksumFailed { code });
         }
-        len == self.len()
+        Ok(code)
     }
-    /// Checks if a provided text represents a valid digit code of this profile
+    /// Like `check_char_code` but only tells if the code is valid
+    fn is_char_code_valid<'a, I: Iterator<Item = &'a str>>(&'a self, chars: I) -> bool {
+        self.check_char_code(chars).is_ok()
+    }
+    /// Checks if a provided text represents a valid digit code of this profile and returns it in its canonical form
+    ///
+    /// Only the separator of the profile's grouping is tolerated (unless it's part of the alphabet),
+    /// other separators make the code invalid. Every char is checked in its canonical form, see `normalize_char`.
+    ///
+    /// ```
+    /// use yew_digit_code::{DigitCodeProfile, DynamicCodeProfile, Grouping, TotpCodeProfile};
     ///
-    /// This splits the text in chars which will be done differently if unicode-segmentation is disabled.
+    /// assert!(TotpCodeProfile::<6>::new().is_str_code_valid("123456"));
+    /// assert!(!TotpCodeProfile::<6>::new().is_str_code_valid("1.2 3-4_5:6"));
     ///
-    /// If you use chars in your alphabet that are longer than one unicode codepoint it could
-    /// result in wrong behaviour.
+    /// let profile = DynamicCodeProfile {
+    ///     grouping: Grouping::every(3, "-"),
+    ///     ..DynamicCodeProfile::new(6, "0123456789")
+    /// };
+    /// assert_eq!(profile.check_str_code("123-456"), Ok("123456".to_string()));
+    /// assert!(!profile.is_str_code_valid("123 456"));
+    /// ```
+    fn check_str_code(&self, code: &str) -> Result<String, DigitCodeError> {
+        let separator = self.grouping().separator;
+        let strip = !separator.is_empty()
+            && !Grapheme::split(&separator).any(|chr| self.char_matches_alphabet_impl(chr));
+        let code = if strip {
+            code.replace(&separator, "")
+        } else {
+            code.to_string()
+        };
+        let chars: Vec<Grapheme> = Grapheme::split(&code)
+            .map(|chr| self.normalize_char(chr))
+            .collect();
+        self.check_char_code(chars.iter().map(Grapheme::as_str))
+    }
+    /// Like `check_str_code` but only tells if the code is valid
     fn is_str_code_valid(&self, code: &str) -> bool {
-        #[cfg(feature = "unicode-segmentation")]
-        let v = code.graphemes(true);
-        #[cfg(not(feature = "unicode-segmentation"))]
-        let v = code.chars();
-        self.is_char_code_valid(v.into_iter())
+        self.check_str_code(code).is_ok()
     }
 
     /// This checks if the provided code is valid.
-    /// The user is responsible for splitting a text into chars/str
-    fn valid_char_code(&self, chars: &[RefStrOrChar<'_>]) -> Option<String> {
+    /// The user is responsible for splitting a text into chars
+    fn valid_char_code(&self, chars: &[&str]) -> Option<String> {
         if self.is_char_code_valid(chars.iter().cloned()) {
             #[cfg(feature = "itertools")]
             let res = chars.into_iter().join("");
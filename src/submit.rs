@@ -0,0 +1,53 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::DigitCodeError;
+
+/// The future an asynchronous submit callback returns.
+///
+/// It resolves to `Err` with a message if the code got rejected.
+pub type SubmitFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+/// The state of the last asynchronous submission
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SubmitState {
+    /// Nothing was submitted since the last edit
+    #[default]
+    Idle,
+    /// The future is still running, the inputs are disabled
+    Pending,
+    /// The code was accepted
+    Success,
+    /// The code was rejected with the given message
+    Error(String),
+    /// The last input was rejected, e.g. the code failed the validation of the profile and wasn't submitted
+    Invalid(DigitCodeError),
+}
+
+/// What happens with the code after an asynchronous submission failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SubmitErrorPolicy {
+    /// All digits get removed and the first one gets focussed
+    #[default]
+    ClearAndRefocus,
+    /// The code is kept and every digit gets selected, so the next char or `Backspace` replaces the whole code
+    KeepAndSelectAll,
+    /// The code is kept and the container gets the `shake` class until the next edit
+    Shake,
+}
+
+impl SubmitState {
+    /// The css classes of the container for this state
+    pub fn classes(&self, policy: SubmitErrorPolicy) -> &'static [&'static str] {
+        match (self, policy) {
+            (SubmitState::Idle, _) => &[],
+            (SubmitState::Pending, _) => &["pending"],
+            (SubmitState::Success, _) => &["success"],
+            (SubmitState::Error(_), SubmitErrorPolicy::Shake) => &["error", "shake"],
+            (SubmitState::Error(_), _) => &["error"],
+            (SubmitState::Invalid(_), _) => &["invalid"],
+        }
+    }
+}
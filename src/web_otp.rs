@@ -0,0 +1,143 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use js_sys::{Array, Object, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{window, AbortController, CredentialRequestOptions, DomException};
+use yew::prelude::*;
+
+/// The result of a request to the [WebOTP API](https://developer.mozilla.org/en-US/docs/Web/API/WebOTP_API)
+/// that is reported to the `onwebotp` callback of `CodeDigitInput`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WebOtpOutcome {
+    /// The browser received a valid code, it was filled in and submitted
+    Received(String),
+    /// The browser received a code that isn't valid for the profile
+    Invalid(String),
+    /// The browser doesn't support the WebOTP API
+    Unsupported,
+    /// The user or the browser denied the request
+    Denied,
+    /// No code was received before the timeout
+    Timeout,
+    /// The request was aborted because the component got removed
+    Aborted,
+    /// Any other error reported by the browser
+    Failed(String),
+}
+
+impl WebOtpOutcome {
+    fn from_error(error: JsValue) -> Self {
+        let Some(exception) = error.dyn_ref::<DomException>() else {
+            return Self::Failed(format!("{error:?}"));
+        };
+        match exception.name().as_str() {
+            "AbortError" => Self::Aborted,
+            "NotAllowedError" => Self::Denied,
+            "NotSupportedError" => Self::Unsupported,
+            "TimeoutError" => Self::Timeout,
+            _ => Self::Failed(exception.message()),
+        }
+    }
+}
+
+fn is_supported() -> bool {
+    window()
+        .map(|window| Reflect::has(&window, &JsValue::from_str("OTPCredential")).unwrap_or(false))
+        .unwrap_or(false)
+}
+
+async fn request_code(controller: &AbortController) -> Result<String, WebOtpOutcome> {
+    let navigator = window().ok_or(WebOtpOutcome::Unsupported)?.navigator();
+
+    let otp = Object::new();
+    Reflect::set(
+        &otp,
+        &JsValue::from_str("transport"),
+        &Array::of1(&JsValue::from_str("sms")),
+    )
+    .map_err(WebOtpOutcome::from_error)?;
+    let options = CredentialRequestOptions::new();
+    options.set_signal(&controller.signal());
+    Reflect::set(&options, &JsValue::from_str("otp"), &otp).map_err(WebOtpOutcome::from_error)?;
+
+    let promise = navigator
+        .credentials()
+        .get_with_options(&options)
+        .map_err(WebOtpOutcome::from_error)?;
+    let credential = JsFuture::from(promise)
+        .await
+        .map_err(WebOtpOutcome::from_error)?;
+    Reflect::get(&credential, &JsValue::from_str("code"))
+        .ok()
+        .and_then(|code| code.as_string())
+        .ok_or_else(|| WebOtpOutcome::Failed("The credential doesn't contain a code".to_string()))
+}
+
+/// Requests a code with the WebOTP API as soon as `enabled` is true.
+///
+/// `oncode` receives the code and returns whether it was accepted.
+/// The request gets aborted when the component is removed.
+#[hook]
+pub(crate) fn use_web_otp(
+    enabled: bool,
+    timeout: Option<Duration>,
+    oncode: Callback<String, bool>,
+    onoutcome: Callback<WebOtpOutcome>,
+) {
+    // the request lives longer than a render so it needs the latest callbacks
+    let callbacks = use_mut_ref(|| (oncode.clone(), onoutcome.clone()));
+    *callbacks.borrow_mut() = (oncode, onoutcome);
+
+    use_effect_with(enabled, move |enabled| {
+        let controller = if !*enabled {
+            None
+        } else if !is_supported() {
+            callbacks.borrow().1.emit(WebOtpOutcome::Unsupported);
+            None
+        } else {
+            AbortController::new().ok()
+        };
+
+        if let Some(controller) = controller.clone() {
+            let timed_out = Rc::new(Cell::new(false));
+            if let Some(timeout) = timeout {
+                let controller = controller.clone();
+                let timed_out = timed_out.clone();
+                spawn_local(async move {
+                    yew::platform::time::sleep(timeout).await;
+                    timed_out.set(true);
+                    controller.abort();
+                });
+            }
+            spawn_local(async move {
+                let outcome = match request_code(&controller).await {
+                    Ok(code) => {
+                        #[cfg(feature = "log")]
+                        log::debug!("WebOTP received a code: {code:?}");
+                        let oncode = callbacks.borrow().0.clone();
+                        if oncode.emit(code.clone()) {
+                            WebOtpOutcome::Received(code)
+                        } else {
+                            WebOtpOutcome::Invalid(code)
+                        }
+                    }
+                    Err(WebOtpOutcome::Aborted) if timed_out.get() => WebOtpOutcome::Timeout,
+                    Err(outcome) => outcome,
+                };
+                #[cfg(feature = "log")]
+                log::debug!("WebOTP request finished: {outcome:?}");
+                let onoutcome = callbacks.borrow().1.clone();
+                onoutcome.emit(outcome);
+            });
+        }
+        move || {
+            if let Some(controller) = controller {
+                controller.abort();
+            }
+        }
+    });
+}
@@ -0,0 +1,121 @@
+//! Runs the WebOTP request of the component against a stand-in for `navigator.credentials`.
+//!
+//! `wasm-pack test --headless --firefox -- --features web-otp`
+#![cfg(all(target_arch = "wasm32", feature = "web-otp"))]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use js_sys::Function;
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use yew::prelude::*;
+use yew_digit_code::{TotpInput, WebOtpOutcome};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq)]
+struct Props {
+    timeout: Option<Duration>,
+    submit_code: Callback<String>,
+    onwebotp: Callback<WebOtpOutcome>,
+}
+
+#[function_component(WebOtpInput)]
+fn web_otp_input(props: &Props) -> Html {
+    html!(
+        <TotpInput
+            web_otp={true}
+            web_otp_timeout={props.timeout}
+            submit_code={props.submit_code.clone()}
+            onwebotp={props.onwebotp.clone()}
+        />
+    )
+}
+
+/// Replaces `navigator.credentials.get` with a function that has the given body and an `options` parameter
+fn stub_credentials(get: &str) {
+    Function::new_no_args(&format!(
+        r#"
+        window.OTPCredential = function () {{}};
+        Object.defineProperty(navigator, "credentials", {{
+            value: {{ get: function (options) {{ {get} }} }},
+            configurable: true,
+        }});
+        "#
+    ))
+    .call0(&wasm_bindgen::JsValue::UNDEFINED)
+    .unwrap();
+}
+
+/// Renders the component and waits for the outcome of the request and the submitted code
+async fn request(get: &str, timeout: Option<Duration>) -> (WebOtpOutcome, Option<String>) {
+    stub_credentials(get);
+    let outcome = Rc::new(RefCell::new(None));
+    let submitted = Rc::new(RefCell::new(None));
+
+    let document = web_sys::window().unwrap().document().unwrap();
+    let root = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&root).unwrap();
+    let props = Props {
+        timeout,
+        submit_code: {
+            let submitted = submitted.clone();
+            Callback::from(move |code| *submitted.borrow_mut() = Some(code))
+        },
+        onwebotp: {
+            let outcome = outcome.clone();
+            Callback::from(move |result| *outcome.borrow_mut() = Some(result))
+        },
+    };
+    let app = yew::Renderer::<WebOtpInput>::with_root_and_props(root, props).render();
+
+    for _ in 0..100 {
+        if outcome.borrow().is_some() {
+            break;
+        }
+        yew::platform::time::sleep(Duration::from_millis(10)).await;
+    }
+    app.destroy();
+    let outcome = outcome.borrow_mut().take().expect("no WebOTP outcome");
+    let submitted = submitted.borrow_mut().take();
+    (outcome, submitted)
+}
+
+#[wasm_bindgen_test]
+async fn received_code_is_submitted() {
+    let result = request(r#"return Promise.resolve({ code: "123456" });"#, None).await;
+    assert_eq!(
+        result,
+        (
+            WebOtpOutcome::Received("123456".into()),
+            Some("123456".into())
+        )
+    );
+}
+
+#[wasm_bindgen_test]
+async fn invalid_code_is_ignored() {
+    let result = request(r#"return Promise.resolve({ code: "12ab" });"#, None).await;
+    assert_eq!(result, (WebOtpOutcome::Invalid("12ab".into()), None));
+}
+
+#[wasm_bindgen_test]
+async fn denied_request() {
+    let get = r#"return Promise.reject(new DOMException("denied", "NotAllowedError"));"#;
+    assert_eq!(request(get, None).await, (WebOtpOutcome::Denied, None));
+}
+
+#[wasm_bindgen_test]
+async fn request_times_out() {
+    // never resolves, only rejects once the component aborts the request
+    let get = r#"
+        return new Promise(function (resolve, reject) {
+            options.signal.addEventListener("abort", function () {
+                reject(new DOMException("aborted", "AbortError"));
+            });
+        });
+    "#;
+    let result = request(get, Some(Duration::from_millis(50))).await;
+    assert_eq!(result, (WebOtpOutcome::Timeout, None));
+}